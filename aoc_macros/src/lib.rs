@@ -3,83 +3,264 @@ extern crate proc_macro;
 use proc_macro::TokenStream;
 use proc_macro2::{Ident, Span};
 use quote::quote;
-use syn::{
-    parse_macro_input, parse_str, Block, FnArg, ItemFn, Path, ReturnType, Signature, Stmt,
-    Visibility,
-};
+use syn::{parse_macro_input, Item, ItemImpl, LitInt, LitStr};
 
+/// Generates the `main` of the bench target. It benchmarks the registered
+/// solvers of every day selected by `bench::Selection::from_env` in a criterion
+/// group named after the day, parsing each input once for every parser they
+/// use. Only the inputs of the selected days are read.
 #[proc_macro]
 pub fn benchmark(_: TokenStream) -> TokenStream {
-    let mut input = ItemFn {
-        attrs: vec![],
-        vis: Visibility::Inherited,
-        sig: Signature {
-            constness: None,
-            asyncness: None,
-            unsafety: None,
-            abi: None,
-            fn_token: Default::default(),
-            ident: Ident::new("benchmark", Span::call_site()),
-            generics: Default::default(),
-            paren_token: Default::default(),
-            inputs: Default::default(),
-            variadic: None,
-            output: ReturnType::Default,
-        },
-        block: Box::new(Block {
-            brace_token: Default::default(),
-            stmts: vec![],
-        }),
-    };
-
-    let criterion: FnArg = parse_str("c: &mut Criterion").unwrap();
-    input.sig.inputs.push_value(criterion);
-
-    for day in 1..=25 {
-        if !std::path::Path::exists(format!("./src/days/day_{:02}.rs", day).as_ref()) {
-            continue;
+    quote!(
+        fn bench_event(
+            c: &mut criterion::Criterion,
+            event: advent_of_code_rust_criterion::Event,
+            part: Option<advent_of_code_rust_criterion::Part>,
+        ) {
+            let registrations: Vec<_> = advent_of_code_rust_criterion::registrations()
+                .into_iter()
+                .filter(|registration| registration.event == event)
+                .filter(|registration| part.is_none_or(|part| part == registration.part))
+                .collect();
+            if registrations.is_empty() {
+                return;
+            }
+            let inputs = match advent_of_code_rust_criterion::read_inputs(event) {
+                Ok(inputs) => inputs,
+                Err(why) => {
+                    eprintln!("Not benchmarking {}: {}", event, why);
+                    return;
+                }
+            };
+            let mut group = c.benchmark_group(event.to_string());
+            for input in &inputs {
+                // Parts parsing their input the same way share one parse
+                // benchmark, `None` if parsing failed.
+                let mut parsed_inputs: Vec<(std::any::TypeId, Option<Box<dyn std::any::Any>>)> =
+                    vec![];
+                for registration in &registrations {
+                    let solver = registration.solver;
+                    let parsed = match solver.parser() {
+                        Some(parser) => {
+                            if !parsed_inputs
+                                .iter()
+                                .any(|(parsed_by, _)| *parsed_by == parser)
+                            {
+                                group.bench_with_input(
+                                    criterion::BenchmarkId::new(
+                                        format!("Parse | {}", registration.variant),
+                                        &input.name,
+                                    ),
+                                    &input.text,
+                                    |b, text| b.iter(|| solver.parse(text)),
+                                );
+                                let parsed = solver
+                                    .parse(&input.text)
+                                    .inspect_err(|why| {
+                                        eprintln!(
+                                            "Not benchmarking {} | {}: {}",
+                                            event, input.name, why
+                                        )
+                                    })
+                                    .ok();
+                                parsed_inputs.push((parser, parsed));
+                            }
+                            let parsed = parsed_inputs
+                                .iter()
+                                .find(|(parsed_by, _)| *parsed_by == parser)
+                                .and_then(|(_, parsed)| parsed.as_deref());
+                            match parsed {
+                                Some(parsed) => Some(parsed),
+                                None => continue,
+                            }
+                        }
+                        None => None,
+                    };
+                    group.bench_with_input(
+                        criterion::BenchmarkId::new(
+                            format!(
+                                "Part {} | {} | Solve",
+                                registration.part, registration.variant
+                            ),
+                            &input.name,
+                        ),
+                        &input.text,
+                        |b, text| b.iter(|| solver.solve(text, parsed)),
+                    );
+                }
+            }
+            group.finish();
         }
-        let puzzle_input: Stmt = parse_str(&*format!(
-            "let input = std::fs::read_to_string(\"data/inputs/day_{:02}.txt\").unwrap();",
-            day
-        ))
-        .unwrap();
-        input.block.stmts.push(puzzle_input);
-        for part in ['a', 'b'] {
-            let bench: Stmt =
-                parse_str(&*format!("c.bench_function(\"Day {:02} | Part {:02}\", |b| b.iter(|| advent_of_code_rust_criterion::days::day_{:02}::part_{}(&*input)));", day, part.to_uppercase(), day, part))
-                    .unwrap();
-            input.block.stmts.push(bench);
+
+        fn main() {
+            let selection = match advent_of_code_rust_criterion::bench::Selection::from_env() {
+                Ok(selection) => selection,
+                Err(why) => {
+                    eprintln!("{}", why);
+                    std::process::exit(1);
+                }
+            };
+            let mut c = criterion::Criterion::default().configure_from_args();
+            for event in advent_of_code_rust_criterion::registered_events() {
+                if selection.includes(event) {
+                    bench_event(&mut c, event, selection.part);
+                }
+            }
+            c.final_summary();
         }
-    }
+    )
+    .into()
+}
 
-    quote!(#input).into()
+struct AocArgs {
+    year: Option<LitInt>,
+    day: Option<LitInt>,
+    part: Option<Ident>,
+    variant: Option<LitStr>,
 }
 
-#[proc_macro]
-pub fn day_function_vec(input: TokenStream) -> TokenStream {
-    let vec_name = parse_macro_input!(input as Ident);
+impl AocArgs {
+    fn parse(args: TokenStream) -> syn::Result<Self> {
+        let mut aoc_args = AocArgs {
+            year: None,
+            day: None,
+            part: None,
+            variant: None,
+        };
+        let parser = syn::meta::parser(|meta| {
+            if meta.path.is_ident("year") {
+                aoc_args.year = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("day") {
+                aoc_args.day = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("part") {
+                aoc_args.part = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("variant") {
+                aoc_args.variant = Some(meta.value()?.parse()?);
+            } else {
+                return Err(meta.error("expected `year`, `day`, `part` or `variant`"));
+            }
+            Ok(())
+        });
+        syn::parse::Parser::parse(parser, args)?;
+        Ok(aoc_args)
+    }
+}
 
-    let mut days = vec![];
-    for day in 1..=25 {
-        if !std::path::Path::exists(format!("./src/days/day_{:02}.rs", day).as_ref()) {
-            continue;
-        }
+/// Registers a function or a `Solution` impl as the solver for a day.
+///
+/// `#[aoc(year = 2023, day = 5, part = B)]` on a `fn(&str) -> Option<T>`
+/// registers that part, `#[aoc(year = 2023, day = 9)]` on an `impl Solution`
+/// registers both parts.
+/// An optional `variant = "name"` lets several solvers share one part.
+#[proc_macro_attribute]
+pub fn aoc(args: TokenStream, item: TokenStream) -> TokenStream {
+    let args = match AocArgs::parse(args) {
+        Ok(args) => args,
+        Err(why) => return why.to_compile_error().into(),
+    };
+    let item = parse_macro_input!(item as Item);
 
-        let mut parts = vec![];
-        for part in ['a', 'b'] {
-            let part: Path =
-                parse_str(&*format!("crate::days::day_{:02}::part_{}", day, part)).unwrap();
-            parts.push(part)
+    let year = match &args.year {
+        None => {
+            return syn::Error::new(Span::call_site(), "missing `year = YYYY`")
+                .to_compile_error()
+                .into()
+        }
+        Some(year) => match year.base10_parse::<u16>() {
+            Ok(number @ 2015..) => number,
+            _ => {
+                return syn::Error::new(year.span(), "year must be 2015 or later")
+                    .to_compile_error()
+                    .into()
+            }
+        },
+    };
+    let day = match &args.day {
+        None => {
+            return syn::Error::new(Span::call_site(), "missing `day = N`")
+                .to_compile_error()
+                .into()
+        }
+        Some(day) => match day.base10_parse::<u8>() {
+            Ok(number @ 1..=25) => number,
+            _ => {
+                return syn::Error::new(day.span(), "day must be between 1 and 25")
+                    .to_compile_error()
+                    .into()
+            }
+        },
+    };
+    if let Some(part) = &args.part {
+        if part != "A" && part != "B" {
+            return syn::Error::new(part.span(), "part must be `A` or `B`")
+                .to_compile_error()
+                .into();
         }
-        days.push(parts);
     }
+    let variant = args
+        .variant
+        .as_ref()
+        .map(LitStr::value)
+        .unwrap_or_else(|| "default".to_string());
+
+    let registrations = match &item {
+        Item::Fn(item_fn) => {
+            let Some(part) = &args.part else {
+                return syn::Error::new(Span::call_site(), "missing `part = A` or `part = B`")
+                    .to_compile_error()
+                    .into();
+            };
+            let fn_name = &item_fn.sig.ident;
+            vec![quote!(inventory::submit! {
+                crate::Registration {
+                    event: crate::Event::new(crate::Year(#year), crate::Day(#day)),
+                    part: crate::Part::#part,
+                    variant: #variant,
+                    solver: &#fn_name,
+                }
+            })]
+        }
+        Item::Impl(ItemImpl {
+            trait_: Some(_),
+            self_ty,
+            ..
+        }) => {
+            let parts = match &args.part {
+                Some(part) => vec![part.clone()],
+                None => vec![
+                    Ident::new("A", Span::call_site()),
+                    Ident::new("B", Span::call_site()),
+                ],
+            };
+            parts
+                .into_iter()
+                .map(|part| {
+                    quote!(
+                        inventory::submit! {
+                            crate::Registration {
+                                event: crate::Event::new(crate::Year(#year), crate::Day(#day)),
+                                part: crate::Part::#part,
+                                variant: #variant,
+                                solver: &crate::solution::SolutionPart::<#self_ty>::new(crate::Part::#part),
+                            }
+                        }
+                    )
+                })
+                .collect()
+        }
+        _ => {
+            return syn::Error::new(
+                Span::call_site(),
+                "#[aoc] can only be used on functions and `impl Solution` blocks",
+            )
+            .to_compile_error()
+            .into()
+        }
+    };
 
-    let num_days = days.len();
     quote!(
-        pub const #vec_name: [[fn(&str) -> Option<Box<dyn Display>>; 2]; #num_days] = [
-            #([#(|input| #days(input).map(|o| Box::new(o) as _)),*]),*
-        ];
+        #item
+        #(#registrations)*
     )
     .into()
 }
@@ -0,0 +1,226 @@
+use std::fmt::{Display, Formatter};
+
+/// The heap use of a single run of a part.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub struct AllocStats {
+    pub allocations: u64,
+    /// Every byte allocated, including the ones freed again. Reallocations
+    /// count as allocating their new size.
+    pub bytes: u64,
+    /// The most bytes allocated at the same time.
+    pub peak_bytes: u64,
+    /// The bytes still allocated at the end, e.g. by a parsed input. Not
+    /// passed on by worker processes.
+    pub retained_bytes: u64,
+}
+
+impl AllocStats {
+    /// The heap use of a run of `self` followed by `next`, which happens
+    /// while what `self` retained is still allocated.
+    pub fn followed_by(self, next: AllocStats) -> AllocStats {
+        AllocStats {
+            allocations: self.allocations + next.allocations,
+            bytes: self.bytes + next.bytes,
+            peak_bytes: self.peak_bytes.max(self.retained_bytes + next.peak_bytes),
+            retained_bytes: self.retained_bytes + next.retained_bytes,
+        }
+    }
+}
+
+impl Display for AllocStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} allocated, {} peak",
+            self.allocations,
+            format_bytes(self.bytes),
+            format_bytes(self.peak_bytes)
+        )
+    }
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+/// Runs `f` and counts the allocations it makes on the current thread, if the
+/// `alloc-stats` feature is enabled.
+#[cfg(feature = "alloc-stats")]
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    let (value, stats) = counting::measure(f);
+    (value, Some(stats))
+}
+
+/// Runs `f` and counts the allocations it makes on the current thread, if the
+/// `alloc-stats` feature is enabled.
+#[cfg(not(feature = "alloc-stats"))]
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    (f(), None)
+}
+
+/// A global allocator that counts per thread, so that days solved at the same
+/// time don't count each other's allocations.
+#[cfg(feature = "alloc-stats")]
+mod counting {
+    use super::AllocStats;
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    #[derive(Copy, Clone)]
+    struct Counters {
+        allocations: u64,
+        bytes: u64,
+        /// Negative if more was freed than allocated, which happens when
+        /// memory allocated before a measurement is freed during it.
+        live_bytes: i64,
+        peak_bytes: i64,
+    }
+
+    thread_local! {
+        static COUNTERS: Cell<Counters> = const {
+            Cell::new(Counters {
+                allocations: 0,
+                bytes: 0,
+                live_bytes: 0,
+                peak_bytes: 0,
+            })
+        };
+    }
+
+    fn update(f: impl FnOnce(&mut Counters)) {
+        // The counters are gone while the thread shuts down.
+        let _ = COUNTERS.try_with(|counters| {
+            let mut updated = counters.get();
+            f(&mut updated);
+            counters.set(updated);
+        });
+    }
+
+    fn record_alloc(size: usize) {
+        update(|counters| {
+            counters.allocations += 1;
+            counters.bytes += size as u64;
+            counters.live_bytes += size as i64;
+            counters.peak_bytes = counters.peak_bytes.max(counters.live_bytes);
+        });
+    }
+
+    fn record_dealloc(size: usize) {
+        update(|counters| counters.live_bytes -= size as i64);
+    }
+
+    struct CountingAllocator;
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                record_alloc(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                record_alloc(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            record_dealloc(layout.size());
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = System.realloc(ptr, layout, new_size);
+            if !new_ptr.is_null() {
+                record_dealloc(layout.size());
+                record_alloc(new_size);
+            }
+            new_ptr
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    pub fn measure<T>(f: impl FnOnce() -> T) -> (T, AllocStats) {
+        let mut start = COUNTERS.with(Cell::get);
+        start.peak_bytes = start.live_bytes;
+        COUNTERS.with(|counters| counters.set(start));
+        let value = f();
+        let end = COUNTERS.with(Cell::get);
+        let stats = AllocStats {
+            allocations: end.allocations - start.allocations,
+            bytes: end.bytes - start.bytes,
+            peak_bytes: (end.peak_bytes - start.live_bytes).max(0) as u64,
+            retained_bytes: (end.live_bytes - start.live_bytes).max(0) as u64,
+        };
+        (value, stats)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(1000), "1000 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    fn test_followed_by_keeps_retained_bytes_allocated() {
+        let parse = AllocStats {
+            allocations: 3,
+            bytes: 4096,
+            peak_bytes: 3072,
+            retained_bytes: 2048,
+        };
+        let solve = AllocStats {
+            allocations: 1,
+            bytes: 1024,
+            peak_bytes: 1024,
+            retained_bytes: 0,
+        };
+        let part = parse.followed_by(solve);
+        assert_eq!(part.allocations, 4);
+        assert_eq!(part.bytes, 5120);
+        assert_eq!(part.peak_bytes, 3072);
+        assert_eq!(parse.followed_by(parse).peak_bytes, 5120);
+    }
+
+    #[test]
+    fn test_measure_allocations() {
+        let (kept, stats) = measure(|| {
+            let kept: Vec<u8> = vec![1; 1000];
+            let freed: Vec<u8> = vec![2; 500];
+            drop(freed);
+            kept
+        });
+        assert_eq!(kept.len(), 1000);
+        if cfg!(feature = "alloc-stats") {
+            let stats = stats.unwrap();
+            assert_eq!(stats.allocations, 2);
+            assert_eq!(stats.bytes, 1500);
+            assert_eq!(stats.peak_bytes, 1500);
+            assert_eq!(stats.retained_bytes, 1000);
+        } else {
+            assert_eq!(stats, None);
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// The answer of one part.
+///
+/// Signed and unsigned integers compare equal if they hold the same value, and
+/// text that spans several lines, like letters drawn in ASCII art, is always a
+/// `Grid` so that it compares equal to a recorded grid.
+#[derive(Debug, Clone, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    Text(String),
+    Grid(Vec<String>),
+}
+
+impl Answer {
+    pub fn is_multi_line(&self) -> bool {
+        matches!(self, Answer::Grid(_))
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Unsigned(a), Answer::Unsigned(b)) => a == b,
+            (Answer::Signed(a), Answer::Signed(b)) => a == b,
+            (Answer::Unsigned(a), Answer::Signed(b)) | (Answer::Signed(b), Answer::Unsigned(a)) => {
+                u64::try_from(*b) == Ok(*a)
+            }
+            (Answer::Text(a), Answer::Text(b)) => a == b,
+            (Answer::Grid(a), Answer::Grid(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Unsigned(value) => write!(f, "{}", value),
+            Answer::Signed(value) => write!(f, "{}", value),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Grid(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::Unsigned(value.into())
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Unsigned(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Unsigned(value as u64)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Signed(value)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(value: isize) -> Self {
+        Answer::Signed(value as i64)
+    }
+}
+
+impl From<Vec<String>> for Answer {
+    fn from(rows: Vec<String>) -> Self {
+        Answer::Grid(rows)
+    }
+}
+
+/// Numbers become integers, text spanning several lines becomes a grid.
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        if let Ok(value) = text.parse() {
+            Answer::Unsigned(value)
+        } else if let Ok(value) = text.parse() {
+            Answer::Signed(value)
+        } else if text.contains('\n') {
+            Answer::Grid(text.lines().map(String::from).collect())
+        } else {
+            Answer::Text(text.to_string())
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::from(text.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answers_compare_by_value() {
+        assert_eq!(Answer::from(42u32), Answer::from(42i64));
+        assert_ne!(Answer::from(-42i64), Answer::from("42"));
+        assert_eq!(Answer::from("-3"), Answer::Signed(-3));
+        assert_eq!(
+            Answer::from("#.\n.#"),
+            Answer::Grid(vec!["#.".to_string(), ".#".to_string()])
+        );
+        assert!(Answer::from("#.\n.#").is_multi_line());
+        assert_eq!(Answer::from("abc"), Answer::Text("abc".to_string()));
+    }
+
+    #[test]
+    fn test_answers_serialize_as_plain_values() {
+        let answers = [
+            Answer::from(7u64),
+            Answer::from(-7i64),
+            Answer::from("abc"),
+            Answer::from("#.\n.#"),
+        ];
+        let json = serde_json::to_string(&answers).unwrap();
+        assert_eq!(json, r##"[7,-7,"abc",["#.",".#"]]"##);
+        let round_trip: Vec<Answer> = serde_json::from_str(&json).unwrap();
+        assert_eq!(round_trip, answers);
+    }
+}
@@ -0,0 +1,155 @@
+use crate::inputs::DEFAULT_INPUT;
+use crate::{data_path, Answer, Event, Part};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::fs::{create_dir_all, read_to_string, write};
+use std::io::{self, ErrorKind};
+use std::path::PathBuf;
+
+/// Recorded answers live in `data/<year>/answers/day_XX.txt`, one
+/// `<part>: <answer>` line per part. Newlines in multi-line answers are stored
+/// as `\n`. Answers for a named input are kept in
+/// `data/<year>/answers/day_XX/<name>.txt`.
+fn answers_path(event: Event, input: &str) -> PathBuf {
+    let answers_dir = data_path(event.year, "answers");
+    if input == DEFAULT_INPUT {
+        answers_dir.join(format!("day_{}.txt", event.day))
+    } else {
+        answers_dir
+            .join(format!("day_{}", event.day))
+            .join(format!("{}.txt", input))
+    }
+}
+
+fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(answer: &str) -> String {
+    let mut unescaped = String::with_capacity(answer.len());
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                unescaped.push('\n');
+                chars.next();
+            }
+            ('\\', Some('\\')) => {
+                unescaped.push('\\');
+                chars.next();
+            }
+            _ => unescaped.push(c),
+        }
+    }
+    unescaped
+}
+
+pub(crate) fn parse_answers(content: &str) -> BTreeMap<Part, Answer> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let (part, answer) = line.split_once(": ")?;
+            Some((part.trim().parse().ok()?, Answer::from(unescape(answer))))
+        })
+        .collect()
+}
+
+fn format_answers(answers: &BTreeMap<Part, Answer>) -> String {
+    answers
+        .iter()
+        .map(|(part, answer)| format!("{}: {}\n", part, escape(&answer.to_string())))
+        .collect()
+}
+
+/// The recorded answers for one input of a day. An input without an answers
+/// file simply has no known answers yet.
+pub fn read_answers(event: Event, input: &str) -> io::Result<BTreeMap<Part, Answer>> {
+    match read_to_string(answers_path(event, input)) {
+        Ok(content) => Ok(parse_answers(&content)),
+        Err(why) if why.kind() == ErrorKind::NotFound => Ok(BTreeMap::new()),
+        Err(why) => Err(why),
+    }
+}
+
+/// Stores `answers` as the known-good answers for one input of a day, keeping
+/// the recorded answers of parts that are not part of `answers`.
+pub fn record_answers(
+    event: Event,
+    input: &str,
+    answers: &BTreeMap<Part, Answer>,
+) -> io::Result<()> {
+    let mut recorded = read_answers(event, input)?;
+    recorded.extend(answers.clone());
+    let path = answers_path(event, input);
+    if let Some(answers_dir) = path.parent() {
+        create_dir_all(answers_dir)?;
+    }
+    write(path, format_answers(&recorded))
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Verdict {
+    Correct,
+    Wrong { expected: Answer },
+    Unknown,
+}
+
+impl Verdict {
+    pub fn new(answer: Option<&Answer>, expected: Option<&Answer>) -> Self {
+        match (answer, expected) {
+            (_, None) => Verdict::Unknown,
+            (Some(answer), Some(expected)) if answer == expected => Verdict::Correct,
+            (_, Some(expected)) => Verdict::Wrong {
+                expected: expected.clone(),
+            },
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Wrong { expected } if expected.is_multi_line() => {
+                write!(f, "wrong, expected\n{}", expected)
+            }
+            Verdict::Wrong { expected } => write!(f, "wrong, expected {}", expected),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answers_round_trip() {
+        let answers = BTreeMap::from([
+            (Part::A, Answer::from(1234u32)),
+            (Part::B, Answer::from("#..#\n\\.##")),
+        ]);
+        assert_eq!(parse_answers(&format_answers(&answers)), answers);
+    }
+
+    #[test]
+    fn test_answers_path_per_input() {
+        let event = Event::new("2023".parse().unwrap(), "09".parse().unwrap());
+        assert!(answers_path(event, DEFAULT_INPUT).ends_with("2023/answers/day_09.txt"));
+        assert!(answers_path(event, "alice").ends_with("2023/answers/day_09/alice.txt"));
+    }
+
+    #[test]
+    fn test_verdict() {
+        let answer = Answer::from(42u32);
+        assert_eq!(
+            Verdict::new(Some(&Answer::from(42i64)), Some(&answer)),
+            Verdict::Correct
+        );
+        assert_eq!(Verdict::new(Some(&answer), None), Verdict::Unknown);
+        assert_eq!(
+            Verdict::new(None, Some(&answer)),
+            Verdict::Wrong { expected: answer }
+        );
+    }
+}
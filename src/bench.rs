@@ -0,0 +1,250 @@
+use crate::{Day, Days, Event, Part, Year};
+use std::error::Error;
+use std::ffi::OsString;
+use std::fmt::{Display, Formatter};
+use std::process::Command;
+use std::str::FromStr;
+
+/// The bench target generated by `benchmark!`.
+pub const BENCH_TARGET: &str = "aoc_benchmark";
+
+/// A single day like `5` or a range of days like `1-5`, both ends included.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct DayRange {
+    pub start: Day,
+    pub end: Day,
+}
+
+impl DayRange {
+    pub fn days(self) -> Days {
+        Days::bounded(self.start, self.end)
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct ParseDayRangeError {}
+impl Display for ParseDayRangeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "This does not seem to be a day or a range of days like 1-5"
+        )
+    }
+}
+impl Default for ParseDayRangeError {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParseDayRangeError {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Error for ParseDayRangeError {}
+
+impl FromStr for DayRange {
+    type Err = ParseDayRangeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start, end) = s.split_once('-').unwrap_or((s, s));
+        let start: Day = start
+            .trim()
+            .parse()
+            .map_err(|_| ParseDayRangeError::new())?;
+        let end: Day = end.trim().parse().map_err(|_| ParseDayRangeError::new())?;
+        if start > end {
+            return Err(ParseDayRangeError::new());
+        }
+        Ok(DayRange { start, end })
+    }
+}
+
+/// What to do with a criterion baseline.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Baseline {
+    Save(String),
+    Compare(String),
+}
+
+/// Which benchmarks to run, passed to the bench target in environment
+/// variables, so that only the selected days are run and read their inputs.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Selection {
+    /// Every year if `None`.
+    pub year: Option<Year>,
+    /// Every day if empty.
+    pub days: Vec<Day>,
+    /// Both parts if `None`.
+    pub part: Option<Part>,
+}
+
+const YEAR_VAR: &str = "AOC_BENCH_YEAR";
+/// A comma separated list of days.
+const DAYS_VAR: &str = "AOC_BENCH_DAYS";
+const PART_VAR: &str = "AOC_BENCH_PART";
+
+#[derive(Debug, Clone)]
+pub struct ParseSelectionError {
+    var: &'static str,
+    value: String,
+}
+impl Display for ParseSelectionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid ${}: {}", self.var, self.value)
+    }
+}
+
+impl ParseSelectionError {
+    pub fn new(var: &'static str, value: impl Into<String>) -> Self {
+        Self {
+            var,
+            value: value.into(),
+        }
+    }
+}
+
+impl Error for ParseSelectionError {}
+
+impl Selection {
+    /// The `days` of `year`, every day if there are none, and `part`.
+    pub fn new(year: Year, days: &[DayRange], part: Option<Part>) -> Self {
+        let mut days: Vec<Day> = days.iter().flat_map(|range| range.days()).collect();
+        days.sort();
+        days.dedup();
+        Selection {
+            year: Some(year),
+            days,
+            part,
+        }
+    }
+
+    pub fn includes(&self, event: Event) -> bool {
+        self.year.is_none_or(|year| year == event.year)
+            && (self.days.is_empty() || self.days.contains(&event.day))
+    }
+
+    pub fn includes_part(&self, event: Event, part: Part) -> bool {
+        self.includes(event) && self.part.is_none_or(|selected| selected == part)
+    }
+
+    fn env_vars(&self) -> [(&'static str, String); 3] {
+        let days: Vec<String> = self.days.iter().map(Day::to_string).collect();
+        [
+            (
+                YEAR_VAR,
+                self.year.map(|year| year.to_string()).unwrap_or_default(),
+            ),
+            (DAYS_VAR, days.join(",")),
+            (
+                PART_VAR,
+                self.part.map(|part| part.to_string()).unwrap_or_default(),
+            ),
+        ]
+    }
+
+    /// The selection `cargo_bench` passed on, everything if it is run by
+    /// `cargo bench` directly.
+    pub fn from_env() -> Result<Self, ParseSelectionError> {
+        let var = |name| std::env::var(name).unwrap_or_default();
+        let year = var(YEAR_VAR);
+        let days = var(DAYS_VAR);
+        let part = var(PART_VAR);
+        Ok(Selection {
+            year: match year.trim() {
+                "" => None,
+                year => Some(
+                    year.parse()
+                        .map_err(|_| ParseSelectionError::new(YEAR_VAR, year))?,
+                ),
+            },
+            days: days
+                .split(',')
+                .filter(|day| !day.trim().is_empty())
+                .map(|day| {
+                    day.trim()
+                        .parse()
+                        .map_err(|_| ParseSelectionError::new(DAYS_VAR, &days))
+                })
+                .collect::<Result<_, _>>()?,
+            part: match part.trim() {
+                "" => None,
+                part => Some(
+                    part.parse()
+                        .map_err(|_| ParseSelectionError::new(PART_VAR, part))?,
+                ),
+            },
+        })
+    }
+}
+
+/// `cargo bench` for the benchmarks of `selection`.
+pub fn cargo_bench(selection: &Selection, baseline: Option<&Baseline>) -> Command {
+    let cargo = std::env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo"));
+    let mut command = Command::new(cargo);
+    command
+        .args(["bench", "--bench", BENCH_TARGET])
+        .envs(selection.env_vars());
+    match baseline {
+        Some(Baseline::Save(name)) => command.args(["--", "--save-baseline", name]),
+        Some(Baseline::Compare(name)) => command.args(["--", "--baseline", name]),
+        None => &mut command,
+    };
+    command
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(s: &str) -> DayRange {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_day_ranges() {
+        assert_eq!(range("5").days().count(), 1);
+        assert_eq!(range("3-7").days().count(), 5);
+        assert!("7-3".parse::<DayRange>().is_err());
+        assert!("0-3".parse::<DayRange>().is_err());
+        assert!("1-".parse::<DayRange>().is_err());
+    }
+
+    #[test]
+    fn test_selection() {
+        let year = "2023".parse().unwrap();
+        let event = |day: &str| Event::new(year, day.parse().unwrap());
+        let all_days = Selection::new(year, &[], None);
+        assert!(all_days.includes_part(event("25"), Part::B));
+        assert!(!all_days.includes(Event::new("2022".parse().unwrap(), "01".parse().unwrap())));
+
+        let selection =
+            Selection::new(year, &[range("9"), range("1-3"), range("2")], Some(Part::B));
+        assert_eq!(selection.days.len(), 4);
+        assert!(selection.includes_part(event("02"), Part::B));
+        assert!(!selection.includes_part(event("02"), Part::A));
+        assert!(!selection.includes(event("04")));
+    }
+
+    #[test]
+    fn test_cargo_bench_passes_selection_and_baseline() {
+        let year = "2023".parse().unwrap();
+        let selection = Selection::new(year, &[range("5"), range("7")], Some(Part::A));
+        let baseline = Baseline::Compare("main".to_string());
+        let command = cargo_bench(&selection, Some(&baseline));
+        let args: Vec<_> = command.get_args().collect();
+        assert_eq!(
+            args,
+            ["bench", "--bench", BENCH_TARGET, "--", "--baseline", "main"]
+        );
+        let envs: Vec<_> = command
+            .get_envs()
+            .map(|(name, value)| (name.to_str().unwrap(), value.unwrap().to_str().unwrap()))
+            .collect();
+        assert!(envs.contains(&(YEAR_VAR, "2023")));
+        assert!(envs.contains(&(DAYS_VAR, "05,07")));
+        assert!(envs.contains(&(PART_VAR, "A")));
+    }
+}
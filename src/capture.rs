@@ -0,0 +1,64 @@
+use std::cell::{Cell, RefCell};
+use std::panic::{catch_unwind, set_hook, take_hook, AssertUnwindSafe, PanicHookInfo};
+use std::sync::Once;
+
+static INSTALL_HOOK: Once = Once::new();
+
+thread_local! {
+    static CAPTURING: Cell<bool> = const { Cell::new(false) };
+    static CAPTURED: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+fn describe(info: &PanicHookInfo) -> String {
+    let payload = info.payload();
+    let message = payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("Box<dyn Any>");
+    match info.location() {
+        Some(location) => format!("panicked at {}: {}", location, message),
+        None => format!("panicked: {}", message),
+    }
+}
+
+/// Panics on a thread that is currently capturing are recorded instead of
+/// printed, every other panic still goes to the previously installed hook.
+fn install_hook() {
+    INSTALL_HOOK.call_once(|| {
+        let previous = take_hook();
+        set_hook(Box::new(move |info| {
+            if CAPTURING.get() {
+                CAPTURED.set(Some(describe(info)));
+            } else {
+                previous(info);
+            }
+        }));
+    });
+}
+
+/// Runs `f`, turning a panic into an error holding its message and location.
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    install_hook();
+    let was_capturing = CAPTURING.replace(true);
+    let outcome = catch_unwind(AssertUnwindSafe(f));
+    CAPTURING.set(was_capturing);
+    outcome.map_err(|_| {
+        CAPTURED
+            .take()
+            .unwrap_or_else(|| "panicked without a message".to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catch_panic_reports_message_and_location() {
+        let why = catch_panic(|| -> u32 { panic!("no {} here", "input") }).unwrap_err();
+        assert!(why.starts_with("panicked at src/capture.rs:"));
+        assert!(why.ends_with(": no input here"));
+        assert_eq!(catch_panic(|| 42), Ok(42));
+    }
+}
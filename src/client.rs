@@ -0,0 +1,172 @@
+use crate::config;
+use reqwest::blocking::{Client as HttpClient, RequestBuilder};
+use reqwest::header::{HeaderMap, HeaderValue, COOKIE};
+use reqwest::redirect::Policy;
+use std::cell::Cell;
+use std::env;
+use std::fs::{create_dir_all, read_to_string, write};
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::thread::sleep;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// The least time between two requests to the server, unless `aoc.toml` sets
+/// a `request_interval`.
+pub const DEFAULT_REQUEST_INTERVAL: Duration = Duration::from_secs(5);
+
+/// When the last request was sent, in milliseconds since the Unix epoch, so
+/// that the interval also holds across separate runs. Kept in the data
+/// directory.
+const LAST_REQUEST_FILE: &str = ".last_request";
+
+/// Where the session cookie is looked up, the same places `aoc-client` uses.
+const SESSION_ENV_VAR: &str = "ADVENT_OF_CODE_SESSION";
+const HIDDEN_SESSION_FILE: &str = ".adventofcode.session";
+const SESSION_FILE: &str = "adventofcode.session";
+
+/// The session cookie from `$ADVENT_OF_CODE_SESSION`,
+/// `~/.adventofcode.session` or `adventofcode.session` in the config
+/// directory.
+pub fn session_cookie() -> io::Result<String> {
+    if let Ok(cookie) = env::var(SESSION_ENV_VAR) {
+        if !cookie.trim().is_empty() {
+            return Ok(cookie.trim().to_string());
+        }
+    }
+    let path = [
+        dirs::home_dir().map(|dir| dir.join(HIDDEN_SESSION_FILE)),
+        dirs::config_dir().map(|dir| dir.join(SESSION_FILE)),
+    ]
+    .into_iter()
+    .flatten()
+    .find(|path| path.is_file())
+    .ok_or_else(|| {
+        io::Error::new(
+            ErrorKind::NotFound,
+            format!(
+                "no session cookie in ${} or ~/{}",
+                SESSION_ENV_VAR, HIDDEN_SESSION_FILE
+            ),
+        )
+    })?;
+    Ok(read_to_string(path)?.trim().to_string())
+}
+
+/// A client for the puzzle server that sends at most one request per
+/// interval.
+pub struct Client {
+    base_url: String,
+    http: HttpClient,
+    request_interval: Duration,
+    last_request: Cell<Option<Duration>>,
+    last_request_file: Option<PathBuf>,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session_cookie: &str) -> io::Result<Self> {
+        let cookie = HeaderValue::from_str(&format!("session={}", session_cookie))
+            .map_err(|why| io::Error::new(ErrorKind::InvalidInput, why))?;
+        let http = HttpClient::builder()
+            .default_headers(HeaderMap::from_iter([(COOKIE, cookie)]))
+            .redirect(Policy::none())
+            .build()
+            .map_err(io::Error::other)?;
+        Ok(Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            http,
+            request_interval: DEFAULT_REQUEST_INTERVAL,
+            last_request: Cell::new(None),
+            last_request_file: None,
+        })
+    }
+
+    /// A client for the server from `aoc.toml`, or for `base_url` if given,
+    /// using the session cookie from its usual places.
+    pub fn from_config(base_url: Option<String>) -> io::Result<Self> {
+        let (_, config) = config::load()?;
+        let base_url = base_url
+            .or(config.base_url)
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_string());
+        let mut client = Self::new(&base_url, &session_cookie()?)?
+            .with_last_request_file(&config::data_dir().join(LAST_REQUEST_FILE));
+        if let Some(request_interval) = config.request_interval {
+            client.request_interval = humantime::parse_duration(&request_interval)
+                .map_err(|why| io::Error::new(ErrorKind::InvalidData, why))?;
+        }
+        Ok(client)
+    }
+
+    pub fn with_request_interval(mut self, request_interval: Duration) -> Self {
+        self.request_interval = request_interval;
+        self
+    }
+
+    /// Shares the time of the last request with every other client and run
+    /// using the same file.
+    pub fn with_last_request_file(mut self, path: &Path) -> Self {
+        self.last_request_file = Some(path.to_path_buf());
+        self
+    }
+
+    /// Waits until the request interval has passed since the last request.
+    fn throttle(&self) -> io::Result<()> {
+        let since_epoch = || SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+        let mut last_request = self.last_request.get();
+        if let Some(path) = &self.last_request_file {
+            match read_to_string(path) {
+                Ok(content) => {
+                    let recorded = content.trim().parse().ok().map(Duration::from_millis);
+                    last_request = last_request.max(recorded);
+                }
+                Err(why) if why.kind() == ErrorKind::NotFound => {}
+                Err(why) => return Err(why),
+            }
+        }
+        if let Some(last_request) = last_request {
+            let next_request = last_request + self.request_interval;
+            if let Some(wait) = next_request.checked_sub(since_epoch()) {
+                sleep(wait);
+            }
+        }
+
+        let now = since_epoch();
+        self.last_request.set(Some(now));
+        if let Some(path) = &self.last_request_file {
+            if let Some(dir) = path.parent() {
+                create_dir_all(dir)?;
+            }
+            // Rounded up, so that waiting for the recorded time never falls short.
+            write(path, now.as_micros().div_ceil(1000).to_string())?;
+        }
+        Ok(())
+    }
+
+    fn send(&self, request: RequestBuilder) -> io::Result<String> {
+        self.throttle()?;
+        let response = request.send().map_err(io::Error::other)?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Err(io::Error::new(
+                ErrorKind::NotFound,
+                format!("{} not found", response.url()),
+            ));
+        }
+        response
+            .error_for_status()
+            .and_then(|response| response.text())
+            .map_err(io::Error::other)
+    }
+
+    pub fn get(&self, path: &str) -> io::Result<String> {
+        self.send(self.http.get(format!("{}{}", self.base_url, path)))
+    }
+
+    pub fn post_form(&self, path: &str, form: &[(&str, String)]) -> io::Result<String> {
+        self.send(
+            self.http
+                .post(format!("{}{}", self.base_url, path))
+                .form(form),
+        )
+    }
+}
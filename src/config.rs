@@ -0,0 +1,99 @@
+use serde::Deserialize;
+use std::env::{self, current_dir};
+use std::fs::read_to_string;
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// The project configuration file, looked up from the working directory
+/// upwards.
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+const DEFAULT_DATA_DIR: &str = "data";
+
+static DATA_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// Settings read from `aoc.toml`. Relative paths are relative to the file.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Where inputs, examples and answers are kept, `data` by default.
+    pub data_dir: Option<PathBuf>,
+    /// The server inputs are downloaded from and answers submitted to, the
+    /// real one by default.
+    pub base_url: Option<String>,
+    /// The least time between two requests to the server, like `"5s"`.
+    pub request_interval: Option<String>,
+}
+
+impl Config {
+    pub fn parse(content: &str) -> io::Result<Self> {
+        toml::from_str(content).map_err(|why| io::Error::new(ErrorKind::InvalidData, why))
+    }
+}
+
+/// The directory with the nearest `aoc.toml`, or the root of this crate if
+/// there is none, so that the data is found from any working directory.
+pub fn project_root() -> PathBuf {
+    current_dir()
+        .ok()
+        .and_then(|cwd| {
+            cwd.ancestors()
+                .find(|dir| dir.join(CONFIG_FILE).is_file())
+                .map(Path::to_path_buf)
+        })
+        .unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")))
+}
+
+/// Reads `aoc.toml` from the project root. Without one, every setting keeps
+/// its default.
+pub fn load() -> io::Result<(PathBuf, Config)> {
+    let root = project_root();
+    match read_to_string(root.join(CONFIG_FILE)) {
+        Ok(content) => Ok((root, Config::parse(&content)?)),
+        Err(why) if why.kind() == ErrorKind::NotFound => Ok((root, Config::default())),
+        Err(why) => Err(why),
+    }
+}
+
+/// Uses `data_dir` instead of the one from `aoc.toml`. Has to be called before
+/// any data is read, returns `false` otherwise.
+pub fn set_data_dir(data_dir: PathBuf) -> bool {
+    DATA_DIR.set(data_dir).is_ok()
+}
+
+/// The directory all puzzle data lives in: the one given with `set_data_dir`,
+/// the one from `aoc.toml`, or `data` in the project root.
+pub fn data_dir() -> &'static Path {
+    DATA_DIR.get_or_init(|| {
+        let (root, config) =
+            load().unwrap_or_else(|why| panic!("Invalid {}: {}", CONFIG_FILE, why));
+        root.join(
+            config
+                .data_dir
+                .as_deref()
+                .unwrap_or(Path::new(DEFAULT_DATA_DIR)),
+        )
+    })
+}
+
+/// Where cargo builds to, `target` in the project root unless
+/// `$CARGO_TARGET_DIR` is set.
+pub fn target_dir() -> PathBuf {
+    env::var_os("CARGO_TARGET_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| project_root().join("target"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_config() {
+        let config = Config::parse("data_dir = \"../inputs\"\n").unwrap();
+        assert_eq!(config.data_dir, Some(PathBuf::from("../inputs")));
+        assert_eq!(Config::parse("").unwrap(), Config::default());
+        assert!(Config::parse("data-dir = \"x\"").is_err());
+    }
+}
@@ -0,0 +1,111 @@
+use crate::client::Client;
+use crate::{config, input_path, Event};
+use chrono::{DateTime, FixedOffset, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::{create_dir_all, read_to_string, write};
+use std::io::{self, ErrorKind};
+use std::path::PathBuf;
+
+/// Puzzles unlock at midnight in the puzzle release timezone, UTC-5.
+const RELEASE_UTC_OFFSET: i32 = -5 * 60 * 60;
+
+/// When and how much was downloaded for each day, kept in
+/// `data/downloads.json`.
+const DOWNLOADS_FILE: &str = "downloads.json";
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Fetch {
+    /// Seconds since the Unix epoch.
+    pub fetched_at: i64,
+    pub bytes: usize,
+}
+
+fn downloads_path() -> PathBuf {
+    config::data_dir().join(DOWNLOADS_FILE)
+}
+
+/// Every download so far, keyed by `<year>/<day>`.
+pub fn read_downloads() -> io::Result<BTreeMap<String, Fetch>> {
+    match read_to_string(downloads_path()) {
+        Ok(content) => serde_json::from_str(&content)
+            .map_err(|why| io::Error::new(ErrorKind::InvalidData, why)),
+        Err(why) if why.kind() == ErrorKind::NotFound => Ok(BTreeMap::new()),
+        Err(why) => Err(why),
+    }
+}
+
+fn record_download(event: Event, fetch: Fetch) -> io::Result<()> {
+    let mut downloads = read_downloads()?;
+    downloads.insert(format!("{}/{}", event.year, event.day), fetch);
+    let content = serde_json::to_string_pretty(&downloads).map_err(io::Error::other)?;
+    write(downloads_path(), content)
+}
+
+pub fn unlock_time(event: Event) -> DateTime<Utc> {
+    FixedOffset::east_opt(RELEASE_UTC_OFFSET)
+        .unwrap()
+        .with_ymd_and_hms(
+            i32::from(event.year),
+            12,
+            usize::from(event.day) as u32 + 1,
+            0,
+            0,
+            0,
+        )
+        .unwrap()
+        .with_timezone(&Utc)
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Download {
+    /// The input is already there, nothing was requested.
+    Cached,
+    Fetched(Fetch),
+    /// The puzzle has not unlocked yet at the given time.
+    Locked {
+        unlocks_at: DateTime<Utc>,
+    },
+}
+
+/// Downloads the input of a day unless it was downloaded before or the puzzle
+/// has not unlocked yet at `now`.
+pub fn download_input(client: &Client, event: Event, now: DateTime<Utc>) -> io::Result<Download> {
+    let path = input_path(event);
+    if path.is_file() {
+        return Ok(Download::Cached);
+    }
+    let unlocks_at = unlock_time(event);
+    if now < unlocks_at {
+        return Ok(Download::Locked { unlocks_at });
+    }
+
+    let input = client.get(&format!(
+        "/{}/day/{}/input",
+        event.year,
+        usize::from(event.day) + 1
+    ))?;
+    if let Some(inputs_dir) = path.parent() {
+        create_dir_all(inputs_dir)?;
+    }
+    write(&path, &input)?;
+    let fetch = Fetch {
+        fetched_at: now.timestamp(),
+        bytes: input.len(),
+    };
+    record_download(event, fetch.clone())?;
+    Ok(Download::Fetched(fetch))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_puzzles_unlock_at_midnight_utc_minus_5() {
+        let event = Event::new("2023".parse().unwrap(), "01".parse().unwrap());
+        assert_eq!(unlock_time(event).to_rfc3339(), "2023-12-01T05:00:00+00:00");
+        let event = Event::new("2015".parse().unwrap(), "25".parse().unwrap());
+        assert_eq!(unlock_time(event).to_rfc3339(), "2015-12-25T05:00:00+00:00");
+    }
+}
@@ -0,0 +1,126 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Why a part could not be solved for an input, as opposed to a part that is
+/// not solved yet.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum AocError {
+    /// The input could not be parsed. Lines and columns start at 1.
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The input is well-formed, but the puzzle it describes has no answer.
+    Invalid { message: String },
+}
+
+impl AocError {
+    pub fn parse(line: usize, column: usize, message: impl Into<String>) -> Self {
+        AocError::Parse {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    pub fn invalid(message: impl Into<String>) -> Self {
+        AocError::Invalid {
+            message: message.into(),
+        }
+    }
+
+    /// A parse error at `token`, which has to be a slice of `input`.
+    pub fn parse_at(input: &str, token: &str, message: impl Into<String>) -> Self {
+        let (line, column) = location(input, token);
+        AocError::parse(line, column, message)
+    }
+
+    /// Places an error from parsing a single token, which does not know where
+    /// the token came from, at `token` in `input`.
+    pub fn at(self, input: &str, token: &str) -> Self {
+        match self {
+            AocError::Parse { message, .. } | AocError::Invalid { message } => {
+                AocError::parse_at(input, token, message)
+            }
+        }
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        match self {
+            AocError::Parse { line, .. } => Some(*line),
+            AocError::Invalid { .. } => None,
+        }
+    }
+}
+
+/// Where `token` starts in `input`. A token that is not a slice of `input`,
+/// e.g. a copy of part of it, is looked for by its text instead, falling back
+/// to the start of the input.
+fn location(input: &str, token: &str) -> (usize, usize) {
+    let offset = (token.as_ptr() as usize)
+        .checked_sub(input.as_ptr() as usize)
+        .filter(|&offset| offset + token.len() <= input.len())
+        .or_else(|| input.find(token))
+        .unwrap_or(0);
+    let before = &input[..offset];
+    let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocError::Parse {
+                line,
+                column,
+                message,
+            } => write!(
+                f,
+                "Input error at line {}, column {}: {}",
+                line, column, message
+            ),
+            AocError::Invalid { message } => write!(f, "Input error: {}", message),
+        }
+    }
+}
+
+impl Error for AocError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error_location() {
+        let input = "32T3K 765\nT55J5 68x\n";
+        assert_eq!(
+            AocError::parse_at(input, &input[16..19], "invalid bid"),
+            AocError::parse(2, 7, "invalid bid")
+        );
+        assert_eq!(AocError::parse_at(input, &input[..5], "x").line(), Some(1));
+        assert_eq!(
+            AocError::invalid("invalid card X")
+                .at(input, &input[12..])
+                .to_string(),
+            "Input error at line 2, column 3: invalid card X"
+        );
+    }
+
+    #[test]
+    fn test_location_of_a_token_from_elsewhere() {
+        let input = "32T3K 765\nT55J5 68x\n";
+        let copy = input[16..19].to_string();
+        assert_eq!(
+            AocError::parse_at(input, &copy, "invalid bid"),
+            AocError::parse(2, 7, "invalid bid")
+        );
+        assert_eq!(
+            AocError::parse_at(input, "QQQJA", "invalid hand"),
+            AocError::parse(1, 1, "invalid hand")
+        );
+    }
+}
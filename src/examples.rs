@@ -0,0 +1,155 @@
+use crate::answers::parse_answers;
+use crate::{data_path, run_day_parts, Answer, Event, Input, Part, PartResult, Parts, RunConfig};
+use std::collections::BTreeMap;
+use std::fs::{read_dir, read_to_string};
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+
+/// An example from the puzzle text along with the answers it should give.
+///
+/// Examples are kept as `data/<year>/examples/day_XX_a.txt` for part A and
+/// `day_XX_b.txt` for part B. Their expected answers live next to them in
+/// `day_XX_a.expected`, as `<part>: <answer>` lines like recorded answers.
+///
+/// Any number of further examples can be put into `data/<year>/examples/day_XX/`
+/// as `<name>.txt` with a `<name>.expected` for part A, part B or both. Such an
+/// example is run for every part it has an expected answer for, or for both
+/// parts if it has none.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Example {
+    pub name: String,
+    pub path: PathBuf,
+    pub parts: Vec<Part>,
+    pub expected: BTreeMap<Part, Answer>,
+}
+
+impl Example {
+    pub fn expected_path(&self) -> PathBuf {
+        self.path.with_extension("expected")
+    }
+}
+
+fn read_expected(path: &Path) -> io::Result<BTreeMap<Part, Answer>> {
+    match read_to_string(path) {
+        Ok(content) => Ok(parse_answers(&content)),
+        Err(why) if why.kind() == ErrorKind::NotFound => Ok(BTreeMap::new()),
+        Err(why) => Err(why),
+    }
+}
+
+pub fn example_set_dir(event: Event) -> PathBuf {
+    data_path(event.year, "examples").join(format!("day_{}", event.day))
+}
+
+fn example_set(event: Event) -> io::Result<Vec<Example>> {
+    let set_dir = example_set_dir(event);
+    let entries = match read_dir(&set_dir) {
+        Ok(entries) => entries,
+        Err(why) if why.kind() == ErrorKind::NotFound => return Ok(vec![]),
+        Err(why) => return Err(why),
+    };
+    let mut names = vec![];
+    for entry in entries {
+        let path = entry?.path();
+        let extension = path.extension().and_then(|extension| extension.to_str());
+        if !matches!(extension, Some("txt" | "expected")) {
+            continue;
+        }
+        if let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) {
+            names.push(name.to_string());
+        }
+    }
+    names.sort();
+    names.dedup();
+
+    names
+        .into_iter()
+        .map(|name| {
+            let path = set_dir.join(format!("{}.txt", name));
+            let expected = read_expected(&path.with_extension("expected"))?;
+            let parts = if expected.is_empty() {
+                Parts::new().collect()
+            } else {
+                expected.keys().copied().collect()
+            };
+            Ok(Example {
+                name: format!("day_{}/{}", event.day, name),
+                path,
+                parts,
+                expected,
+            })
+        })
+        .collect()
+}
+
+/// Every example of a day that has an input, an expected answer or both:
+/// the ones for a single part first, then the example set ordered by name.
+pub fn examples(event: Event) -> io::Result<Vec<Example>> {
+    let examples_dir = data_path(event.year, "examples");
+    let mut examples = vec![];
+    for part in Parts::new() {
+        let name = format!("day_{}_{}", event.day, part.to_string().to_lowercase());
+        let path = examples_dir.join(format!("{}.txt", name));
+        let mut expected = read_expected(&path.with_extension("expected"))?;
+        expected.retain(|&expected_part, _| expected_part == part);
+        if path.is_file() || !expected.is_empty() {
+            examples.push(Example {
+                name,
+                path,
+                parts: vec![part],
+                expected,
+            });
+        }
+    }
+    examples.extend(example_set(event)?);
+    Ok(examples)
+}
+
+/// Runs the parts of an example, failing if its input is missing.
+pub fn run_example(
+    event: Event,
+    example: &Example,
+    config: &RunConfig,
+) -> io::Result<Vec<PartResult>> {
+    let input = Input {
+        name: example.name.clone(),
+        text: read_to_string(&example.path)?,
+        answers: example.expected.clone(),
+    };
+    Ok(run_day_parts(event, &example.parts, &input, config))
+}
+
+/// A line by line diff of two answers, `-` marking expected and `+` actual
+/// lines.
+pub fn diff(expected: &str, actual: &str) -> String {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+    let mut diff = String::new();
+    for line in 0..expected.len().max(actual.len()) {
+        match (expected.get(line), actual.get(line)) {
+            (Some(expected), Some(actual)) if expected == actual => {
+                diff.push_str(&format!("  {}\n", expected))
+            }
+            (expected, actual) => {
+                if let Some(expected) = expected {
+                    diff.push_str(&format!("- {}\n", expected));
+                }
+                if let Some(actual) = actual {
+                    diff.push_str(&format!("+ {}\n", actual));
+                }
+            }
+        }
+    }
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_marks_changed_lines() {
+        assert_eq!(diff("#.\n.#", "#.\n##\n.."), "  #.\n- .#\n+ ##\n+ ..\n");
+        assert_eq!(diff("281", "280"), "- 281\n+ 280\n");
+    }
+}
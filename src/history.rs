@@ -0,0 +1,267 @@
+use crate::report::{PartResult, Status};
+use crate::{config, Event, Part, DEFAULT_INPUT};
+use chrono::{DateTime, Utc};
+use humantime::format_duration;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter, Write as _};
+use std::fs::{create_dir_all, read_to_string, OpenOptions};
+use std::io::{self, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+
+/// Timings of earlier runs, one JSON object per line.
+const HISTORY_FILE: &str = "history.jsonl";
+
+/// Where the history is kept, `target/aoc-history/history.jsonl`.
+pub fn history_path() -> PathBuf {
+    config::target_dir().join("aoc-history").join(HISTORY_FILE)
+}
+
+/// What timed the parts of a record.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Source {
+    /// `solve-all`, with the time of the puzzle input only.
+    SolveAll,
+    /// `bench`, with the time of the default variant on the puzzle input.
+    Bench,
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_repr = match self {
+            Source::SolveAll => "solve-all",
+            Source::Bench => "bench",
+        };
+        write!(f, "{}", string_repr)
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    /// The commit that was checked out, ending in `-dirty` if it had changes.
+    pub commit: Option<String>,
+    /// Seconds since the Unix epoch.
+    pub recorded_at: i64,
+    pub source: Source,
+    pub year: String,
+    pub day: String,
+    pub part: String,
+    pub time_ns: u128,
+}
+
+impl Record {
+    pub fn time(&self) -> Duration {
+        Duration::from_nanos(self.time_ns as u64)
+    }
+
+    fn is_of(&self, event: Event, part: Part) -> bool {
+        self.year == event.year.to_string()
+            && self.day == event.day.to_string()
+            && self.part == part.to_string()
+    }
+}
+
+/// The time of every part solved on the puzzle input, the mean if it was
+/// run more than once.
+pub fn solved_times(results: &[PartResult]) -> Vec<(Event, Part, Duration)> {
+    results
+        .iter()
+        .filter(|result| result.status == Status::Solved && result.input == DEFAULT_INPUT)
+        .map(|result| {
+            let time = result.stats.map_or(result.elapsed(), |stats| stats.mean);
+            (result.event, result.part, time)
+        })
+        .collect()
+}
+
+/// Records of `times` from `source`, taken at `commit` and `now`.
+pub fn records(
+    source: Source,
+    times: &[(Event, Part, Duration)],
+    commit: Option<String>,
+    now: DateTime<Utc>,
+) -> Vec<Record> {
+    times
+        .iter()
+        .map(|(event, part, time)| Record {
+            commit: commit.clone(),
+            recorded_at: now.timestamp(),
+            source,
+            year: event.year.to_string(),
+            day: event.day.to_string(),
+            part: part.to_string(),
+            time_ns: time.as_nanos(),
+        })
+        .collect()
+}
+
+/// The short hash of the checked out commit, if the project is a git
+/// repository.
+pub fn current_commit() -> Option<String> {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(config::project_root())
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+    };
+    let commit = String::from_utf8(git(&["rev-parse", "--short", "HEAD"])?.stdout).ok()?;
+    let dirty = git(&["status", "--porcelain", "--untracked-files=no"])
+        .is_some_and(|output| !output.stdout.is_empty());
+    let commit = commit.trim();
+    Some(if dirty {
+        format!("{}-dirty", commit)
+    } else {
+        commit.to_string()
+    })
+}
+
+pub fn append(path: &Path, records: &[Record]) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        create_dir_all(dir)?;
+    }
+    let mut lines = String::new();
+    for record in records {
+        lines.push_str(&serde_json::to_string(record).map_err(io::Error::other)?);
+        lines.push('\n');
+    }
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?
+        .write_all(lines.as_bytes())
+}
+
+pub fn read(path: &Path) -> io::Result<Vec<Record>> {
+    let content = match read_to_string(path) {
+        Ok(content) => content,
+        Err(why) if why.kind() == ErrorKind::NotFound => return Ok(vec![]),
+        Err(why) => return Err(why),
+    };
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            serde_json::from_str(line).map_err(|why| io::Error::new(ErrorKind::InvalidData, why))
+        })
+        .collect()
+}
+
+fn format_recorded_at(recorded_at: i64) -> String {
+    DateTime::from_timestamp(recorded_at, 0).map_or_else(String::new, |time| {
+        time.format("%Y-%m-%d %H:%M").to_string()
+    })
+}
+
+/// Every recorded time of a part, oldest first, with the change from the
+/// previous time of the same source, followed by the best time of each
+/// source. `None` if nothing was recorded for the part.
+pub fn trend(records: &[Record], event: Event, part: Part) -> Option<String> {
+    let records: Vec<&Record> = records
+        .iter()
+        .filter(|record| record.is_of(event, part))
+        .collect();
+    if records.is_empty() {
+        return None;
+    }
+
+    let mut trend = String::new();
+    let mut previous: BTreeMap<Source, Duration> = BTreeMap::new();
+    let mut best: BTreeMap<Source, &Record> = BTreeMap::new();
+    for &record in &records {
+        let change =
+            previous
+                .insert(record.source, record.time())
+                .map_or(String::new(), |previous| {
+                    let change =
+                        (record.time().as_secs_f64() / previous.as_secs_f64() - 1.0) * 100.0;
+                    format!("{:+.1}%", change)
+                });
+        writeln!(
+            trend,
+            "{}  {:<14} {:<9} {:>20} {:>8}",
+            format_recorded_at(record.recorded_at),
+            record.commit.as_deref().unwrap_or("-"),
+            record.source,
+            format_duration(record.time()).to_string(),
+            change
+        )
+        .unwrap();
+        let best = best.entry(record.source).or_insert(record);
+        if record.time() < best.time() {
+            *best = record;
+        }
+    }
+    for (source, record) in best {
+        writeln!(
+            trend,
+            "Best {}: {} at {} on {}",
+            source,
+            format_duration(record.time()),
+            record.commit.as_deref().unwrap_or("-"),
+            format_recorded_at(record.recorded_at)
+        )
+        .unwrap();
+    }
+    Some(trend)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::fs::remove_file;
+
+    fn event() -> Event {
+        Event::new("2023".parse().unwrap(), "05".parse().unwrap())
+    }
+
+    fn times_at(source: Source, commit: &str, timestamp: i64, micros: u64) -> Vec<Record> {
+        records(
+            source,
+            &[(event(), Part::A, Duration::from_micros(micros))],
+            Some(commit.to_string()),
+            DateTime::from_timestamp(timestamp, 0).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_append_and_read_history() {
+        let path = env::temp_dir().join(format!("aoc-history-{}.jsonl", std::process::id()));
+        assert_eq!(read(&path).unwrap(), vec![]);
+        let first = times_at(Source::Bench, "abc1234", 0, 200);
+        let second = times_at(Source::SolveAll, "def5678", 60, 300);
+        append(&path, &first).unwrap();
+        append(&path, &second).unwrap();
+        assert_eq!(read(&path).unwrap(), [first, second].concat());
+        remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_trend_compares_times_of_the_same_source() {
+        let records = [
+            times_at(Source::Bench, "abc1234", 0, 200),
+            times_at(Source::SolveAll, "abc1234", 60, 900),
+            times_at(Source::Bench, "def5678", 120, 150),
+            times_at(Source::Bench, "0123abc", 180, 180),
+        ]
+        .concat();
+        let part_a = trend(&records, event(), Part::A).unwrap();
+        let lines: Vec<&str> = part_a.lines().collect();
+        assert_eq!(lines.len(), 6);
+        assert!(lines[0].starts_with("1970-01-01 00:00  abc1234"));
+        assert!(lines[1].trim_end().ends_with("900us"));
+        assert!(lines[2].ends_with("-25.0%"));
+        assert!(lines[3].ends_with("+20.0%"));
+        assert_eq!(
+            lines[4],
+            "Best solve-all: 900us at abc1234 on 1970-01-01 00:01"
+        );
+        assert_eq!(lines[5], "Best bench: 150us at def5678 on 1970-01-01 00:02");
+        assert_eq!(trend(&records, event(), Part::B), None);
+    }
+}
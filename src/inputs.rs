@@ -0,0 +1,87 @@
+use crate::answers::read_answers;
+use crate::{data_path, Answer, Event, Part};
+use std::collections::BTreeMap;
+use std::fs::{read_dir, read_to_string};
+use std::io::{self, ErrorKind};
+use std::path::PathBuf;
+
+/// The name of the input kept in `data/<year>/inputs/day_XX.txt`.
+pub const DEFAULT_INPUT: &str = "default";
+
+/// One puzzle input of a day, along with the answers recorded for it.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Input {
+    pub name: String,
+    pub text: String,
+    pub answers: BTreeMap<Part, Answer>,
+}
+
+impl Input {
+    /// An input without any recorded answers.
+    pub fn new(name: impl Into<String>, text: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            text: text.into(),
+            answers: BTreeMap::new(),
+        }
+    }
+}
+
+pub fn input_path(event: Event) -> PathBuf {
+    data_path(event.year, "inputs").join(format!("day_{}.txt", event.day))
+}
+
+/// Further inputs of a day, e.g. from several people, are kept as
+/// `data/<year>/inputs/day_XX/<name>.txt`.
+pub fn input_set_dir(event: Event) -> PathBuf {
+    data_path(event.year, "inputs").join(format!("day_{}", event.day))
+}
+
+fn input_names(event: Event) -> io::Result<Vec<String>> {
+    let entries = match read_dir(input_set_dir(event)) {
+        Ok(entries) => entries,
+        Err(why) if why.kind() == ErrorKind::NotFound => return Ok(vec![]),
+        Err(why) => return Err(why),
+    };
+    let mut names = vec![];
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().is_some_and(|extension| extension == "txt") {
+            if let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) {
+                names.push(name.to_string());
+            }
+        }
+    }
+    names.sort();
+    Ok(names)
+}
+
+/// Every input of a day with its recorded answers: the default input first,
+/// then the input set ordered by name. Fails if the day has no input at all.
+pub fn read_inputs(event: Event) -> io::Result<Vec<Input>> {
+    let mut inputs = vec![];
+    match read_to_string(input_path(event)) {
+        Ok(text) => inputs.push(Input::new(DEFAULT_INPUT, text)),
+        Err(why) if why.kind() == ErrorKind::NotFound => {}
+        Err(why) => return Err(why),
+    }
+    for name in input_names(event)? {
+        let text = read_to_string(input_set_dir(event).join(format!("{}.txt", name)))?;
+        inputs.push(Input::new(name, text));
+    }
+    if inputs.is_empty() {
+        return Err(io::Error::new(
+            ErrorKind::NotFound,
+            format!(
+                "neither {} nor any inputs in {}",
+                input_path(event).display(),
+                input_set_dir(event).display()
+            ),
+        ));
+    }
+
+    for input in &mut inputs {
+        input.answers = read_answers(event, &input.name)?;
+    }
+    Ok(inputs)
+}
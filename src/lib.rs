@@ -1,16 +1,98 @@
-use aoc_macros::day_function_vec;
+use answers::{record_answers, Verdict};
 use humantime::format_duration;
+use std::any::{Any, TypeId};
+use std::collections::BTreeMap;
 use std::error::Error;
 use std::fmt::{Display, Formatter};
-use std::fs::read_to_string;
 use std::hint::black_box;
+use std::io;
+use std::path::PathBuf;
 use std::process::exit;
 use std::str::FromStr;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
-pub mod days;
+pub mod alloc;
+pub mod answer;
+pub mod answers;
+pub mod bench;
+pub mod capture;
+pub mod client;
+pub mod config;
+pub mod download;
+pub mod error;
+pub mod examples;
+pub mod history;
+pub mod inputs;
+pub mod parallel;
+pub mod perf;
+pub mod registry;
+pub mod report;
+pub mod solution;
+pub mod stats;
+pub mod submit;
+pub mod worker;
+pub mod years;
 
-day_function_vec!(DAY_VEC);
+pub use alloc::AllocStats;
+pub use answer::Answer;
+pub use error::AocError;
+pub use inputs::{input_path, read_inputs, Input, DEFAULT_INPUT};
+pub use registry::{
+    get_solver, is_implemented, registered_events, registered_parts, registered_years,
+    registrations, variants, NotImplementedError, Registration,
+};
+pub use report::{OutputFormat, PartResult, SortOrder, Status};
+pub use solution::{PartOutput, PartSolver, Solution};
+pub use stats::{RunConfig, TimingStats};
+
+/// The year of an Advent of Code event, starting with the first one in 2015.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct Year(u16);
+
+#[derive(Debug, Copy, Clone)]
+pub struct ParseYearError {}
+impl Display for ParseYearError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "This does not seem to be a valid AoC year")
+    }
+}
+impl Default for ParseYearError {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParseYearError {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Error for ParseYearError {}
+
+impl FromStr for Year {
+    type Err = ParseYearError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year_number = s.parse().map_err(|_| ParseYearError::new())?;
+        if year_number < 2015 {
+            return Err(ParseYearError::new());
+        }
+        Ok(Year(year_number))
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<Year> for i32 {
+    fn from(value: Year) -> Self {
+        value.0 as i32
+    }
+}
 
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
 pub struct Day(u8);
@@ -98,6 +180,26 @@ impl From<Day> for usize {
     }
 }
 
+/// A single puzzle day of one year's event, the key solvers are registered
+/// under.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct Event {
+    pub year: Year,
+    pub day: Day,
+}
+
+impl Event {
+    pub const fn new(year: Year, day: Day) -> Self {
+        Self { year, day }
+    }
+}
+
+impl Display for Event {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} Day {}", self.year, self.day)
+    }
+}
+
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
 pub enum Part {
     A,
@@ -123,6 +225,42 @@ impl Display for Part {
     }
 }
 
+#[derive(Debug, Copy, Clone)]
+pub struct ParsePartError {}
+impl Display for ParsePartError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "This does not seem to be a valid AoC part, expected A or B"
+        )
+    }
+}
+impl Default for ParsePartError {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParsePartError {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Error for ParsePartError {}
+
+impl FromStr for Part {
+    type Err = ParsePartError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "a" | "A" => Ok(Part::A),
+            "b" | "B" => Ok(Part::B),
+            _ => Err(ParsePartError::new()),
+        }
+    }
+}
+
 pub struct Parts {
     state: usize,
 }
@@ -146,53 +284,760 @@ impl Iterator for Parts {
     }
 }
 
-pub fn run_and_print_day(day: Day) {
-    let input = match read_to_string(format!("./data/inputs/day_{}.txt", day)) {
-        Ok(content) => content,
+/// The outcome of a parse step on one input, shared by every part of the day
+/// with the same parser.
+struct ParsedInput {
+    parser: TypeId,
+    /// What the input was parsed into, or the status and error of a parse
+    /// that failed.
+    parsed: Result<Box<dyn Any>, (Status, String)>,
+    time: Duration,
+    allocations: Option<AllocStats>,
+    /// Timing samples of the parse step, taken for the first part that needs
+    /// them.
+    samples: Option<Vec<Duration>>,
+}
+
+impl ParsedInput {
+    fn value(&self) -> Option<&dyn Any> {
+        self.parsed.as_ref().ok().map(|parsed| &**parsed)
+    }
+}
+
+/// Runs the parse step of `solver` once. A panicking parser is reported along
+/// with its panic message instead of unwinding further.
+fn parse_input(parser: TypeId, solver: &dyn PartSolver, input: &Input) -> ParsedInput {
+    let ((outcome, time), allocations) = alloc::measure(|| {
+        let start = Instant::now();
+        let outcome = capture::catch_panic(|| black_box(solver.parse(black_box(&input.text))));
+        (outcome, start.elapsed())
+    });
+    let parsed = match outcome {
+        Ok(Ok(parsed)) => Ok(parsed),
+        Ok(Err(why)) => Err((Status::InputError, why.to_string())),
+        Err(why) => Err((Status::Panicked, why)),
+    };
+    ParsedInput {
+        parser,
+        parsed,
+        time,
+        allocations,
+        samples: None,
+    }
+}
+
+/// Solves a part once from what its parse step made of `input`, or from the
+/// raw input if it has none. A part whose parse step failed fails the same
+/// way, without being run.
+fn solve_parsed(
+    event: Event,
+    part: Part,
+    solver: &dyn PartSolver,
+    input: &Input,
+    parsed: Option<&ParsedInput>,
+) -> PartResult {
+    let mut result = PartResult {
+        event,
+        input: input.name.clone(),
+        part,
+        answer: None,
+        expected: input.answers.get(&part).cloned(),
+        status: Status::Unsolved,
+        error: None,
+        parse_time: parsed.map(|parsed| parsed.time),
+        shared_parse: false,
+        solve_time: Duration::ZERO,
+        stats: None,
+        allocations: parsed.and_then(|parsed| parsed.allocations),
+        parse_allocations: parsed
+            .and_then(|parsed| parsed.allocations)
+            .map(|allocations| allocations.allocations),
+    };
+    if let Some(Err((status, why))) = parsed.map(|parsed| &parsed.parsed) {
+        result.status = *status;
+        result.error = Some(why.clone());
+        return result;
+    }
+
+    let parsed = parsed.and_then(ParsedInput::value);
+    let ((outcome, solve_time), allocations) = alloc::measure(|| {
+        let start = Instant::now();
+        let outcome = capture::catch_panic(|| {
+            black_box(solver.solve(black_box(&input.text), black_box(parsed)))
+        });
+        (outcome, start.elapsed())
+    });
+    result.solve_time = solve_time;
+    result.allocations = match (result.allocations, allocations) {
+        (Some(parse), Some(solve)) => Some(parse.followed_by(solve)),
+        (parse, solve) => parse.or(solve),
+    };
+    match outcome {
+        Ok(Ok(Some(answer))) => {
+            result.answer = Some(answer);
+            result.status = Status::Solved;
+        }
+        Ok(Ok(None)) => {}
+        Ok(Err(why)) => {
+            result.status = Status::InputError;
+            result.error = Some(why.to_string());
+        }
         Err(why) => {
-            eprintln!("Failed to get input for day {}: {}", day, why);
-            exit(1);
+            result.status = Status::Panicked;
+            result.error = Some(why);
         }
-    };
+    }
+    result
+}
+
+/// Runs a single solver once, timing parsing and solving separately. A
+/// panicking solver is reported along with its panic message instead of
+/// unwinding further.
+pub fn run_solver(event: Event, part: Part, solver: &dyn PartSolver, input: &Input) -> PartResult {
+    let parsed = solver
+        .parser()
+        .map(|parser| parse_input(parser, solver, input));
+    solve_parsed(event, part, solver, input, parsed.as_ref())
+}
+
+/// Runs the given parts of a day on `input` and hands each result to
+/// `on_part` as soon as it is done. The input is parsed once for every parser
+/// the parts share. If `config` asks for more than one run, timing statistics
+/// are gathered for the parts that did not fail.
+pub fn run_parts(
+    event: Event,
+    parts: &[(Part, &dyn PartSolver)],
+    input: &Input,
+    config: &RunConfig,
+    mut on_part: impl FnMut(PartResult),
+) {
+    let sampled = config.runs > 1 || config.warmup > 0;
+    let mut parsed_inputs: Vec<ParsedInput> = vec![];
+    for &(part, solver) in parts {
+        let mut shared_parse = false;
+        let parsed = solver.parser().map(|parser| {
+            match parsed_inputs
+                .iter()
+                .position(|parsed| parsed.parser == parser)
+            {
+                Some(index) => {
+                    shared_parse = true;
+                    index
+                }
+                None => {
+                    parsed_inputs.push(parse_input(parser, solver, input));
+                    parsed_inputs.len() - 1
+                }
+            }
+        });
+
+        let mut result = solve_parsed(
+            event,
+            part,
+            solver,
+            input,
+            parsed.map(|index| &parsed_inputs[index]),
+        );
+        result.shared_parse = shared_parse;
+        let completed = matches!(result.status, Status::Solved | Status::Unsolved);
+        if completed && sampled {
+            if let Some(index) = parsed {
+                let parsed = &mut parsed_inputs[index];
+                let single_run = parsed.time;
+                parsed.samples.get_or_insert_with(|| {
+                    stats::sample_parse(solver, &input.text, config, single_run)
+                });
+            }
+            let parsed = parsed.map(|index| &parsed_inputs[index]);
+            let (stats, parse_time, solve_time) = stats::sample_solve(
+                solver,
+                &input.text,
+                parsed.and_then(ParsedInput::value),
+                parsed.and_then(|parsed| parsed.samples.as_deref()),
+                config,
+                result.solve_time,
+            );
+            result.parse_time = parse_time;
+            result.solve_time = solve_time;
+            result.stats = Some(stats);
+        }
+        on_part(result);
+    }
+}
 
-    match DAY_VEC.get(usize::from(day)) {
+/// Puzzle data is kept per year, in `<data dir>/<year>/<kind>/`.
+pub fn data_path(year: Year, kind: &str) -> PathBuf {
+    config::data_dir().join(year.to_string()).join(kind)
+}
+
+/// Runs the implemented ones of `parts` of a day on `input`, in a worker
+/// process if `config` has a timeout.
+pub fn run_day_parts(
+    event: Event,
+    parts: &[Part],
+    input: &Input,
+    config: &RunConfig,
+) -> Vec<PartResult> {
+    let solvers: Vec<(Part, &dyn PartSolver)> = parts
+        .iter()
+        .filter_map(|&part| Some((part, get_solver(event, part).ok()?)))
+        .collect();
+    if solvers.is_empty() {
+        return vec![];
+    }
+    match config.timeout {
+        Some(timeout) => {
+            let parts: Vec<Part> = solvers.iter().map(|&(part, _)| part).collect();
+            worker::run_in_worker(event, &parts, input, config, timeout)
+        }
         None => {
-            eprintln!("Day {} is not yet implemented\n", day);
-            exit(1);
+            let mut results = vec![];
+            run_parts(event, &solvers, input, config, |result| {
+                results.push(result)
+            });
+            results
         }
-        Some(part_fns) => {
-            println!("Day {}", day);
+    }
+}
+
+/// Runs every implemented part of a day on `input`.
+pub fn solve_day(event: Event, input: &Input, config: &RunConfig) -> Vec<PartResult> {
+    run_day_parts(event, &Parts::new().collect::<Vec<_>>(), input, config)
+}
+
+/// Runs every implemented part of a day on each of its inputs.
+pub fn run_day(event: Event, config: &RunConfig) -> io::Result<Vec<PartResult>> {
+    Ok(read_inputs(event)?
+        .iter()
+        .flat_map(|input| solve_day(event, input, config))
+        .collect())
+}
+
+/// Names the input along with the day, unless it is the only default input.
+fn heading(event: Event, input: &str) -> String {
+    if input == DEFAULT_INPUT {
+        event.to_string()
+    } else {
+        format!("{} | {}", event, input)
+    }
+}
+
+/// Formats a verdict to follow an answer, nothing if there is no recorded
+/// answer to compare with.
+fn verdict_suffix(result: &PartResult) -> String {
+    match result.verdict() {
+        Verdict::Unknown => String::new(),
+        verdict => format!(" [{}]", verdict),
+    }
+}
+
+/// The parse and solve times of a part, only the solve time if it has no
+/// parse step, followed by its allocations if they were counted.
+fn format_times(result: &PartResult) -> String {
+    let times = match result.parse_time {
+        Some(parse_time) => format!(
+            "parse t≈{}, solve t≈{}",
+            format_duration(parse_time),
+            format_duration(result.solve_time)
+        ),
+        None => format!("solve t≈{}", format_duration(result.solve_time)),
+    };
+    match result.allocations {
+        Some(allocations) => format!("{}, {}", times, allocations),
+        None => times,
+    }
+}
+
+pub fn print_day(event: Event, results: &[PartResult]) {
+    let mut current_input = None;
+    for result in results {
+        if current_input != Some(&result.input) {
+            if current_input.is_some() {
+                println!();
+            }
+            current_input = Some(&result.input);
+            println!("{}", heading(event, &result.input));
             println!("---");
-            for part in Parts::new() {
-                let part_fn = part_fns[usize::from(part)];
-
-                let start = Instant::now();
-                let maybe_result = black_box(part_fn(black_box(input.as_str())));
-                let end = start.elapsed();
-
-                match maybe_result {
-                    None => println!("Part {}: Not solved", part),
-                    Some(result) => {
-                        let result_string = result.to_string();
-                        if result_string.contains('\n') {
-                            println!(
-                                "Part {} (t≈{})\n▼▼▼▼▼▼ \n{}",
-                                part,
-                                format_duration(end),
-                                result_string
-                            );
-                        } else {
-                            println!(
-                                "Part {}: {} (t≈{})",
-                                part,
-                                result_string,
-                                format_duration(end),
-                            )
-                        }
-                    }
+        }
+        let part = result.part;
+        match (&result.status, &result.answer) {
+            (Status::Panicked, _) => println!(
+                "Part {}: Panicked ({})",
+                part,
+                result.error.as_deref().unwrap_or_default()
+            ),
+            (Status::TimedOut, _) => println!(
+                "Part {}: Timed out after {}",
+                part,
+                format_duration(result.elapsed())
+            ),
+            (Status::InputError, _) => println!(
+                "Part {}: {}",
+                part,
+                result.error.as_deref().unwrap_or("Input error")
+            ),
+            (_, None) => println!("Part {}: Not solved", part),
+            (_, Some(result_string)) => {
+                if result_string.is_multi_line() {
+                    println!(
+                        "Part {} ({}){}\n▼▼▼▼▼▼ \n{}",
+                        part,
+                        format_times(result),
+                        verdict_suffix(result),
+                        result_string
+                    );
+                } else {
+                    println!(
+                        "Part {}: {} ({}){}",
+                        part,
+                        result_string,
+                        format_times(result),
+                        verdict_suffix(result),
+                    )
+                }
+            }
+        }
+        if let Some(stats) = result.stats {
+            println!(
+                "        min {}, median {}, mean {} ± {} ({} runs × {} iterations)",
+                format_duration(stats.min),
+                format_duration(stats.median),
+                format_duration(stats.mean),
+                format_duration(stats.std_dev),
+                stats.runs,
+                stats.iterations,
+            );
+        }
+    }
+    println!();
+}
+
+/// Lists every part that panicked, timed out or disagreed with its recorded
+/// answer and every day that could not be run at all, so that failures don't
+/// get lost between the per-day output.
+pub fn print_failures(results: &[PartResult], skipped: &[(Event, String)]) {
+    let failed: Vec<&PartResult> = results.iter().filter(|result| result.failed()).collect();
+    if failed.is_empty() && skipped.is_empty() {
+        return;
+    }
+
+    println!("Failures");
+    println!("---");
+    for result in failed {
+        let heading = heading(result.event, &result.input);
+        match (result.status, result.verdict()) {
+            (Status::TimedOut, _) => println!(
+                "{} Part {}: Timed out after {}",
+                heading,
+                result.part,
+                format_duration(result.elapsed())
+            ),
+            (Status::Panicked, _) => println!(
+                "{} Part {}: {}",
+                heading,
+                result.part,
+                result.error.as_deref().unwrap_or("Panicked")
+            ),
+            (Status::InputError, _) => println!(
+                "{} Part {}: {}",
+                heading,
+                result.part,
+                result.error.as_deref().unwrap_or("Input error")
+            ),
+            (_, verdict) => println!("{} Part {}: Answer is {}", heading, result.part, verdict),
+        }
+    }
+    for (event, why) in skipped {
+        println!("{}: Skipped, {}", event, why);
+    }
+    println!();
+}
+
+/// Solves a day and checks every part against its recorded answer. With
+/// `record`, the current results become the new known-good answers. Returns
+/// whether the inputs could be read, no part disagreed with the answer
+/// recorded before and, with `record`, the answers could be recorded.
+pub fn run_and_verify_day(event: Event, record: bool) -> bool {
+    let inputs = match read_inputs(event) {
+        Ok(inputs) => inputs,
+        Err(why) => {
+            eprintln!("Failed to get input for {}: {}\n", event, why);
+            return false;
+        }
+    };
+
+    let mut all_correct = true;
+    for input in &inputs {
+        let heading = heading(event, &input.name);
+        println!("{}", heading);
+        println!("---");
+        let mut answers = BTreeMap::new();
+        for result in solve_day(event, input, &RunConfig::default()) {
+            let part = result.part;
+            let verdict = result.verdict();
+            if let Verdict::Wrong { .. } = verdict {
+                all_correct = false;
+            }
+
+            match (&result.status, &result.answer) {
+                (Status::Panicked, _) => println!(
+                    "Part {}: Panicked [{}] ({})",
+                    part,
+                    verdict,
+                    result.error.as_deref().unwrap_or_default()
+                ),
+                (Status::TimedOut, _) => println!("Part {}: Timed out [{}]", part, verdict),
+                (Status::InputError, _) => println!(
+                    "Part {}: {} [{}]",
+                    part,
+                    result.error.as_deref().unwrap_or("Input error"),
+                    verdict
+                ),
+                (_, None) => println!("Part {}: Not solved [{}]", part, verdict),
+                (_, Some(answer)) if answer.is_multi_line() => {
+                    println!("Part {} [{}]\n▼▼▼▼▼▼ \n{}", part, verdict, answer)
+                }
+                (_, Some(answer)) => println!("Part {}: {} [{}]", part, answer, verdict),
+            }
+
+            if let Some(answer) = result.answer {
+                answers.insert(part, answer);
+            }
+        }
+
+        if record && !answers.is_empty() {
+            match record_answers(event, &input.name, &answers) {
+                Ok(()) => println!("Recorded answers for {}", heading),
+                Err(why) => {
+                    eprintln!("Failed to record answers for {}: {}", heading, why);
+                    all_correct = false;
+                }
+            }
+        }
+        println!();
+    }
+
+    all_correct
+}
+
+/// Runs every example of a day and compares the answers with the expected
+/// ones, showing a diff for wrong answers. Returns whether no example failed.
+pub fn run_and_print_examples(event: Event) -> bool {
+    let examples = match examples::examples(event) {
+        Ok(examples) => examples,
+        Err(why) => {
+            eprintln!("Failed to read the examples for {}: {}", event, why);
+            return false;
+        }
+    };
+
+    println!("{} | Examples", event);
+    println!("---");
+    let (mut passed, mut failed, mut skipped) = (0, 0, 0);
+    for example in &examples {
+        let results = match examples::run_example(event, example, &RunConfig::default()) {
+            Ok(results) => results,
+            Err(why) => {
+                println!(
+                    "{}: Skipped, failed to read {}: {}",
+                    example.name,
+                    example.path.display(),
+                    why
+                );
+                skipped += 1;
+                continue;
+            }
+        };
+        for result in results {
+            let answer = match (&result.status, &result.answer) {
+                (Status::Panicked, _) => {
+                    format!("Panicked ({})", result.error.as_deref().unwrap_or_default())
+                }
+                (Status::TimedOut, _) => "Timed out".to_string(),
+                (Status::InputError, _) => result.error.clone().unwrap_or_default(),
+                (_, None) => "Not solved".to_string(),
+                (_, Some(answer)) if answer.is_multi_line() => "(multi-line)".to_string(),
+                (_, Some(answer)) => answer.to_string(),
+            };
+            let prefix = format!("{} Part {}", example.name, result.part);
+            match result.verdict() {
+                Verdict::Correct => {
+                    println!("{}: {} [pass]", prefix, answer);
+                    passed += 1;
+                }
+                Verdict::Wrong { expected } => {
+                    println!("{}: {} [fail]", prefix, answer);
+                    print!(
+                        "{}",
+                        examples::diff(
+                            &expected.to_string(),
+                            &result
+                                .answer
+                                .map(|answer| answer.to_string())
+                                .unwrap_or_default()
+                        )
+                    );
+                    failed += 1;
+                }
+                Verdict::Unknown => {
+                    println!(
+                        "{}: {} [no expected answer in {}]",
+                        prefix,
+                        answer,
+                        example.expected_path().display()
+                    );
+                    skipped += 1;
                 }
             }
-            println!();
         }
     }
+    println!(
+        "{} passed, {} failed, {} skipped\n",
+        passed, failed, skipped
+    );
+
+    failed == 0
+}
+
+/// Runs every registered variant of a part on each input of the day and
+/// prints their timings side by side. Returns whether all variants agreed on
+/// the answer for every input.
+pub fn run_and_print_comparison(event: Event, part: Part) -> bool {
+    let registrations = variants(event, part);
+    if registrations.is_empty() {
+        eprintln!("{}\n", NotImplementedError::new(event, part));
+        exit(1);
+    }
+
+    let inputs = match read_inputs(event) {
+        Ok(inputs) => inputs,
+        Err(why) => {
+            eprintln!("Failed to get input for {}: {}", event, why);
+            exit(1);
+        }
+    };
+
+    let mut all_agree = true;
+    for input in &inputs {
+        all_agree &= print_comparison(event, part, &registrations, input);
+    }
+    all_agree
+}
+
+fn print_comparison(
+    event: Event,
+    part: Part,
+    registrations: &[&Registration],
+    input: &Input,
+) -> bool {
+    let rows: Vec<[String; 4]> = registrations
+        .iter()
+        .map(|registration| {
+            let result = run_solver(event, part, registration.solver, input);
+            let answer = match (result.status, result.answer) {
+                (Status::Panicked, _) => "Panicked".to_string(),
+                (Status::TimedOut, _) => "Timed out".to_string(),
+                (Status::InputError, _) => "Input error".to_string(),
+                (_, None) => "Not solved".to_string(),
+                (_, Some(answer)) => answer.to_string(),
+            };
+            [
+                registration.variant.to_string(),
+                answer,
+                result.parse_time.map_or("-".to_string(), |parse_time| {
+                    format_duration(parse_time).to_string()
+                }),
+                format_duration(result.solve_time).to_string(),
+            ]
+        })
+        .collect();
+
+    let header = ["Variant", "Answer", "Parse", "Solve"].map(String::from);
+    let mut widths = header.clone().map(|column| column.chars().count());
+    for row in &rows {
+        for (width, column) in widths.iter_mut().zip(row) {
+            *width = (*width).max(column.chars().count());
+        }
+    }
+
+    println!("{} | Part {}", heading(event, &input.name), part);
+    println!("---");
+    for row in std::iter::once(&header).chain(&rows) {
+        println!(
+            "{:<w0$}  {:>w1$}  {:>w2$}  {:>w3$}",
+            row[0],
+            row[1],
+            row[2],
+            row[3],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+            w3 = widths[3],
+        );
+    }
+
+    let all_agree = rows.iter().all(|row| row[1] == rows[0][1]);
+    if all_agree {
+        println!("All {} variants agree\n", rows.len());
+    } else {
+        println!("Variants disagree on the answer\n");
+    }
+    all_agree
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::SolutionPart;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn event(year: &str, day: &str) -> Event {
+        Event::new(year.parse().unwrap(), day.parse().unwrap())
+    }
+
+    #[test]
+    fn test_registry_lookup_is_keyed_by_day() {
+        let solver = get_solver(event("2023", "09"), Part::A).unwrap();
+        let input = "0 3 6 9 12 15";
+        let parsed = solver.parser().map(|_| solver.parse(input).unwrap());
+        let result = solver.solve(input, parsed.as_deref()).unwrap();
+        assert_eq!(result, Some(Answer::from(18u32)));
+    }
+
+    static PARSES: AtomicUsize = AtomicUsize::new(0);
+
+    struct InputLength;
+
+    impl Solution for InputLength {
+        type Parsed = usize;
+        type AnswerA = usize;
+        type AnswerB = usize;
+
+        fn parse(input: &str) -> Result<Self::Parsed, AocError> {
+            PARSES.fetch_add(1, Ordering::Relaxed);
+            Ok(input.len())
+        }
+
+        fn part_a(parsed: &Self::Parsed) -> Result<Option<Self::AnswerA>, AocError> {
+            Ok(Some(*parsed))
+        }
+
+        fn part_b(parsed: &Self::Parsed) -> Result<Option<Self::AnswerB>, AocError> {
+            Ok(Some(parsed * 2))
+        }
+    }
+
+    fn raw_input_length(input: &str) -> Option<usize> {
+        Some(input.len())
+    }
+
+    #[test]
+    fn test_parts_share_their_parse_step() {
+        let part_a = SolutionPart::<InputLength>::new(Part::A);
+        let part_b = SolutionPart::<InputLength>::new(Part::B);
+        let parts: [(Part, &dyn PartSolver); 3] = [
+            (Part::A, &part_a),
+            (Part::B, &part_b),
+            (Part::B, &raw_input_length),
+        ];
+        let mut results = vec![];
+        run_parts(
+            event("2023", "01"),
+            &parts,
+            &Input::new(DEFAULT_INPUT, "12345"),
+            &RunConfig::default(),
+            |result| results.push(result),
+        );
+        assert_eq!(PARSES.load(Ordering::Relaxed), 1);
+        let answers: Vec<Option<Answer>> = results.iter().map(|r| r.answer.clone()).collect();
+        assert_eq!(
+            answers,
+            [5u32, 10, 5].map(|answer| Some(Answer::from(answer)))
+        );
+        assert!(results[0].parse_time.is_some());
+        assert_eq!(results[1].parse_time, results[0].parse_time);
+        assert_eq!(results[2].parse_time, None);
+        let shared: Vec<bool> = results.iter().map(|r| r.shared_parse).collect();
+        assert_eq!(shared, [false, true, false]);
+    }
+
+    #[test]
+    fn test_input_errors_are_not_panics() {
+        let day_07 = event("2023", "07");
+        let input = Input::new(DEFAULT_INPUT, "32T3K 765\nT5XJ5 684\n");
+        let result = run_solver(
+            day_07,
+            Part::A,
+            get_solver(day_07, Part::A).unwrap(),
+            &input,
+        );
+        assert_eq!(result.status, Status::InputError);
+        assert_eq!(
+            result.error.as_deref(),
+            Some("Input error at line 2, column 3: Failed to parse Card: X")
+        );
+
+        let day_09 = event("2023", "09");
+        let input = Input::new(DEFAULT_INPUT, "0 3 six");
+        let result = run_solver(
+            day_09,
+            Part::B,
+            get_solver(day_09, Part::B).unwrap(),
+            &input,
+        );
+        assert_eq!(result.status, Status::InputError);
+        assert_eq!(
+            result.error.as_deref(),
+            Some("Input error at line 1, column 5: six is not a number")
+        );
+
+        let day_02 = event("2023", "02");
+        let input = Input::new(DEFAULT_INPUT, "Game 1: 3 blue\nGame 2 1 red\n");
+        let result = run_solver(
+            day_02,
+            Part::A,
+            get_solver(day_02, Part::A).unwrap(),
+            &input,
+        );
+        assert_eq!(result.status, Status::InputError);
+        assert_eq!(
+            result.error.as_deref(),
+            Some("Input error at line 2, column 1: Expected a game")
+        );
+
+        let input = Input::new(DEFAULT_INPUT, "Game 1: 3 blue, 99999999999 red\n");
+        let result = run_solver(
+            day_02,
+            Part::B,
+            get_solver(day_02, Part::B).unwrap(),
+            &input,
+        );
+        assert_eq!(result.status, Status::InputError);
+        assert_eq!(
+            result.error.as_deref(),
+            Some("Input error at line 1, column 17: Failed to parse cube count")
+        );
+    }
+
+    #[test]
+    fn test_registry_default_variant_comes_first() {
+        let registrations = variants(event("2023", "05"), Part::B);
+        assert!(registrations.len() > 1);
+        assert_eq!(registrations[0].variant, registry::DEFAULT_VARIANT);
+    }
+
+    #[test]
+    fn test_registry_lookup_missing_day() {
+        let missing = event("2023", "25");
+        assert!(!is_implemented(missing));
+        assert!(get_solver(missing, Part::A).is_err());
+    }
+
+    #[test]
+    fn test_registry_lookup_is_keyed_by_year() {
+        assert!(is_implemented(event("2023", "09")));
+        assert!(!is_implemented(event("2022", "09")));
+        assert!(registered_years().contains(&"2023".parse().unwrap()));
+        assert!("2014".parse::<Year>().is_err());
+    }
 }
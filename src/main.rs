@@ -1,55 +1,120 @@
-use clap::{Parser, Subcommand};
-use std::fs::File;
-use std::io::{ErrorKind, Write};
+use clap::{Args, Parser, Subcommand};
+use std::fs::{create_dir_all, File};
+use std::io::{self, stdin, stdout, ErrorKind, IsTerminal, Read, Write};
+use std::path::{Path, PathBuf};
 
-use advent_of_code_rust_criterion::{run_and_print_day, Day, Days};
-use aoc_client::{AocClient, AocResult, PuzzleDay};
+use advent_of_code_rust_criterion::bench::{self, Baseline, DayRange, Selection};
+use advent_of_code_rust_criterion::client::Client;
+use advent_of_code_rust_criterion::download::{download_input, Download};
+use advent_of_code_rust_criterion::history::{self, Source};
+use advent_of_code_rust_criterion::{
+    config, data_path, examples, input_path, is_implemented, parallel, perf, print_day,
+    print_failures, read_inputs, registered_years, report, run_and_print_comparison,
+    run_and_print_examples, run_and_verify_day, run_day_parts, solve_day, submit, worker, Day,
+    Days, Event, Input, OutputFormat, Part, PartResult, RunConfig, SortOrder, Year, DEFAULT_INPUT,
+};
+use chrono::Utc;
 use std::process::exit;
+use std::time::Duration;
 
 macro_rules! main_fn {
-    ($day:ident) => {
+    ($input_path:expr) => {
         format!(
             r#"fn main() {{
-    let input = include_str!("./data/inputs/day_{}.txt");
+    let input = include_str!("{}");
     println!("Part A: \x1b[1m{{}}\x1b[0m", part_a(input).unwrap());
     println!("Part B: \x1b[1m{{}}\x1b[0m", part_b(input).unwrap());
 }}"#,
-            $day
+            $input_path
         )
     };
 }
 
+macro_rules! solution_main_fn {
+    ($input_path:expr, $solution:ident) => {
+        format!(
+            r#"fn main() {{
+    let input = include_str!("{}");
+    let parsed = {}::parse(input).unwrap();
+    println!("Part A: \x1b[1m{{}}\x1b[0m", {}::part_a(&parsed).unwrap().unwrap());
+    println!("Part B: \x1b[1m{{}}\x1b[0m", {}::part_b(&parsed).unwrap().unwrap());
+}}"#,
+            $input_path, $solution, $solution, $solution
+        )
+    };
+}
+
+const SOLUTION_TRAIT: &str = r#"trait Solution {
+    type Parsed;
+    type AnswerA: std::fmt::Display;
+    type AnswerB: std::fmt::Display;
+
+    fn parse(input: &str) -> Result<Self::Parsed, AocError>;
+    fn part_a(parsed: &Self::Parsed) -> Result<Option<Self::AnswerA>, AocError>;
+    fn part_b(parsed: &Self::Parsed) -> Result<Option<Self::AnswerB>, AocError>;
+}"#;
+
+/// A stand-in for the library's `AocError` in standalone days, with the same
+/// constructors but only a message.
+const AOC_ERROR: &str = r#"#[derive(Debug)]
+pub struct AocError(String);
+
+#[allow(dead_code)]
+impl AocError {
+    fn parse(line: usize, column: usize, message: impl Into<String>) -> Self {
+        AocError(format!("line {}, column {}: {}", line, column, message.into()))
+    }
+
+    fn invalid(message: impl Into<String>) -> Self {
+        AocError(message.into())
+    }
+
+    fn parse_at(_input: &str, token: &str, message: impl Into<String>) -> Self {
+        AocError(format!("{} at {:?}", message.into(), token))
+    }
+
+    fn at(self, _input: &str, token: &str) -> Self {
+        AocError(format!("{} at {:?}", self.0, token))
+    }
+}
+
+impl std::fmt::Display for AocError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Input error: {}", self.0)
+    }
+}"#;
+
 macro_rules! day_content {
-    ($day:ident) => {
+    ($year:ident, $day:ident) => {
         format!(
-            r#"pub fn part_a(input: &str) -> Option<u32> {{
-    None
-}}
+            r#"use crate::{{AocError, Solution}};
+use aoc_macros::aoc;
 
-pub fn part_b(input: &str) -> Option<u32> {{
-    None
-}}
+pub struct Day{};
 
-#[cfg(test)]
-mod tests {{
-    use super::*;
-    use std::fs::read_to_string;
+#[aoc(year = {}, day = {})]
+impl Solution for Day{} {{
+    type Parsed = Vec<String>;
+    type AnswerA = u32;
+    type AnswerB = u32;
 
-    #[test]
-    fn test_day_{}_part_a() {{
-        let input_a = read_to_string("./data/examples/day_{}_a.txt").unwrap();
-        let result = part_a(input_a.as_str());
-        assert_eq!(result, None);
+    fn parse(input: &str) -> Result<Self::Parsed, AocError> {{
+        Ok(input.lines().map(str::to_string).collect())
     }}
 
-    #[test]
-    fn test_day_{}_part_b() {{
-        let input_b = read_to_string("./data/examples/day_{}_b.txt").unwrap();
-        let result = part_b(input_b.as_str());
-        assert_eq!(result, None);
+    fn part_a(_parsed: &Self::Parsed) -> Result<Option<u32>, AocError> {{
+        Ok(None)
     }}
-}}"#,
-            $day, $day, $day, $day
+
+    fn part_b(_parsed: &Self::Parsed) -> Result<Option<u32>, AocError> {{
+        Ok(None)
+    }}
+}}
+"#,
+            $day,
+            $year,
+            usize::from($day) + 1,
+            $day,
         )
     };
 }
@@ -58,23 +123,155 @@ mod tests {{
 struct AoC {
     #[command(subcommand)]
     command: Command,
+    /// The event to work on, defaults to $AOC_YEAR or the latest year with
+    /// solutions
+    #[clap(long, global = true)]
+    year: Option<Year>,
+    /// Directory with the puzzle data, overrides `data_dir` from aoc.toml
+    #[clap(long, global = true)]
+    data_dir: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct SolveArgs {
+    #[clap(long, default_value = "human")]
+    output: OutputFormat,
+    /// Number of timed runs per part
+    #[clap(long, default_value_t = 1)]
+    runs: usize,
+    /// Number of untimed runs per part before timing
+    #[clap(long, default_value_t = 0)]
+    warmup: usize,
+    /// Give up on a part after this long, e.g. "30s" or "2min"
+    #[clap(long)]
+    timeout: Option<humantime::Duration>,
+    /// Order of the summary table after solving several days: day or time
+    #[clap(long, default_value = "day")]
+    sort: SortOrder,
+    /// Number of days to solve at the same time
+    #[clap(long, short, default_value_t = 1)]
+    jobs: usize,
+    /// With several jobs, only check answers in parallel and time every part
+    /// afterwards, one at a time
+    #[clap(long, action)]
+    isolated: bool,
+}
+
+impl SolveArgs {
+    fn run_config(&self) -> RunConfig {
+        RunConfig {
+            runs: self.runs,
+            warmup: self.warmup,
+            timeout: self.timeout.map(Into::into),
+        }
+    }
 }
 
 #[derive(Subcommand)]
 enum Command {
     Solve {
         day: Day,
+        /// Solve this file instead of the puzzle input, or stdin for "-"
+        #[clap(long)]
+        input: Option<PathBuf>,
+        #[command(flatten)]
+        args: SolveArgs,
     },
     SolveMultiple {
         start: Day,
         end: Day,
+        #[command(flatten)]
+        args: SolveArgs,
+    },
+    SolveAll {
+        #[command(flatten)]
+        args: SolveArgs,
+        /// Append the times of the solved parts to the history
+        #[clap(long, action)]
+        history: bool,
+    },
+    Compare {
+        day: Day,
+        part: Part,
+    },
+    /// Run the criterion benchmarks of some days and parts
+    Bench {
+        /// Days like 5 or ranges like 1-5, every day if none are given
+        days: Vec<DayRange>,
+        #[clap(long)]
+        part: Option<Part>,
+        /// Save the results as the baseline with this name
+        #[clap(long)]
+        save_baseline: Option<String>,
+        /// Compare the results with the baseline of this name
+        #[clap(long, conflicts_with = "save_baseline")]
+        baseline: Option<String>,
+        /// Append the times of the benchmarked parts to the history
+        #[clap(long, action)]
+        history: bool,
+    },
+    /// Print the recorded times of a part and the best one
+    History {
+        day: Day,
+        part: Part,
+    },
+    /// Run the benchmarks of some days and parts and fail if any part got
+    /// slower than in a saved baseline
+    PerfCheck {
+        /// Days like 5 or ranges like 1-5, every day if none are given
+        days: Vec<DayRange>,
+        #[clap(long)]
+        part: Option<Part>,
+        /// The saved baseline to compare with
+        #[clap(long, default_value = "main")]
+        baseline: String,
+        /// How much slower than the baseline a part may get, in percent
+        #[clap(long, default_value_t = 5.0)]
+        threshold: f64,
+        /// Save the results as the new baseline if no part got slower, or if
+        /// there is no such baseline yet
+        #[clap(long, action)]
+        save: bool,
+    },
+    /// Run the examples of a day against their expected answers
+    Examples {
+        day: Day,
+    },
+    Verify {
+        day: Option<Day>,
+        #[clap(long, action)]
+        record: bool,
+    },
+    #[command(name = worker::WORKER_COMMAND, hide = true)]
+    Worker {
+        day: Day,
+        #[clap(required = true)]
+        parts: Vec<Part>,
+        #[clap(long, default_value_t = 1)]
+        runs: usize,
+        #[clap(long, default_value_t = 0)]
+        warmup: usize,
     },
-    SolveAll,
     Initialize {
         day: Day,
     },
+    /// Download the puzzle input of a day, or of every unlocked day with --all
     Download {
+        #[clap(required_unless_present = "all")]
+        day: Option<Day>,
+        #[clap(long, action)]
+        all: bool,
+        /// The server to download from, overrides `base_url` from aoc.toml
+        #[clap(long)]
+        base_url: Option<String>,
+    },
+    /// Solve a part on the puzzle input and submit its answer
+    Submit {
         day: Day,
+        part: Part,
+        /// The server to submit to, overrides `base_url` from aoc.toml
+        #[clap(long)]
+        base_url: Option<String>,
     },
     Standalone {
         day: Day,
@@ -83,59 +280,404 @@ enum Command {
     },
 }
 
-fn main() -> AocResult<()> {
+/// The year given with `--year`, falling back to `$AOC_YEAR` and then to the
+/// latest year that has any solutions.
+fn selected_year(year: Option<Year>) -> Year {
+    let year = year
+        .or_else(|| std::env::var("AOC_YEAR").ok()?.parse().ok())
+        .or_else(|| registered_years().last().copied());
+    match year {
+        Some(year) => year,
+        None => {
+            eprintln!("No year given, please pass --year or set AOC_YEAR");
+            exit(1);
+        }
+    }
+}
+
+fn implemented_events(year: Year, days: Days) -> Vec<Event> {
+    days.map(|day| Event::new(year, day))
+        .filter(|&event| is_implemented(event))
+        .collect()
+}
+
+/// Creates the `src/years/yYYYY` module of a year that has no solutions yet.
+fn initialize_year(year: Year) -> io::Result<()> {
+    let module_path = format!("./src/years/y{}.rs", year);
+    if Path::new(&module_path).exists() {
+        return Ok(());
+    }
+    create_dir_all(format!("./src/years/y{}", year))?;
+    File::create(&module_path)?;
+    File::options()
+        .append(true)
+        .open("./src/years.rs")?
+        .write_all(format!("\npub mod y{};", year).as_bytes())?;
+    println!("Initialized {}", year);
+    Ok(())
+}
+
+/// Reads the file given with `--input`, or stdin for `-`, as an input named
+/// after the file.
+fn read_input_override(path: &Path) -> io::Result<Input> {
+    if path == Path::new("-") {
+        let mut input = Input::new("stdin", String::new());
+        stdin().read_to_string(&mut input.text)?;
+        Ok(input)
+    } else {
+        let name = path.file_stem().unwrap_or_default().to_string_lossy();
+        Ok(Input::new(name, std::fs::read_to_string(path)?))
+    }
+}
+
+/// A test module for a standalone day that checks every example with an
+/// expected answer.
+fn example_tests(event: Event, solution: Option<&str>) -> String {
+    let mut tests = String::new();
+    for example in examples::examples(event).unwrap_or_default() {
+        for (part, expected) in &example.expected {
+            let part_fn = format!("part_{}", part.to_string().to_lowercase());
+            let call = match solution {
+                Some(solution) => format!(
+                    "{}::{}(&{}::parse(input).unwrap()).unwrap()",
+                    solution, part_fn, solution
+                ),
+                None => format!("{}(input)", part_fn),
+            };
+            tests.push_str(&format!(
+                r#"
+    #[test]
+    fn test_{}_{}() {{
+        let input = include_str!("{}");
+        let result = {}.into_iter().next().map(|answer| answer.to_string());
+        assert_eq!(result.as_deref(), Some({:?}));
+    }}
+"#,
+                example.name.replace(|c: char| !c.is_alphanumeric(), "_"),
+                part_fn,
+                example.path.display(),
+                call,
+                expected.to_string()
+            ));
+        }
+    }
+    if tests.is_empty() {
+        return tests;
+    }
+    format!(
+        "#[cfg(test)]\nmod example_tests {{\n    use super::*;\n{}}}",
+        tests
+    )
+}
+
+/// Solves `events` on their puzzle inputs, or a single event on `input`.
+/// Returns the results along with whether every day could be run and no part
+/// failed.
+fn solve_days(
+    events: Vec<Event>,
+    input: Option<Input>,
+    args: &SolveArgs,
+) -> (Vec<PartResult>, bool) {
+    let config = args.run_config();
+    let show_summary = events.len() > 1;
+    let mut results = vec![];
+    let mut skipped = vec![];
+    let mut on_day = |event, day_results: parallel::DayResult| match day_results {
+        Ok(day_results) => {
+            if args.output == OutputFormat::Human {
+                print_day(event, &day_results);
+            }
+            results.extend(day_results);
+        }
+        Err(why) => {
+            eprintln!("Failed to get input for {}: {}\n", event, why);
+            skipped.push((event, format!("failed to get input: {}", why)));
+        }
+    };
+    match input {
+        Some(input) => {
+            for &event in &events {
+                on_day(event, Ok(solve_day(event, &input, &config)));
+            }
+        }
+        None => parallel::run_days(&events, &config, args.jobs, args.isolated, on_day),
+    }
+
+    match args.output {
+        OutputFormat::Human => {
+            if show_summary && !results.is_empty() {
+                println!("Summary");
+                println!("---");
+                println!(
+                    "{}",
+                    report::summary_table(&results, args.sort, stdout().is_terminal())
+                );
+            }
+            print_failures(&results, &skipped);
+        }
+        OutputFormat::Json => println!("{}", report::to_json(&results)),
+        OutputFormat::Csv => print!("{}", report::to_csv(&results)),
+    }
+    let succeeded = skipped.is_empty() && !results.iter().any(PartResult::failed);
+    (results, succeeded)
+}
+
+fn record_history(source: Source, times: &[(Event, Part, Duration)]) {
+    let records = history::records(source, times, history::current_commit(), Utc::now());
+    let path = history::history_path();
+    if let Err(why) = history::append(&path, &records) {
+        eprintln!("Failed to record history in {}: {}", path.display(), why);
+        exit(1);
+    }
+    eprintln!("Recorded {} times in {}", records.len(), path.display());
+}
+
+fn main() -> io::Result<()> {
     let args = AoC::parse();
+    match args.data_dir {
+        Some(data_dir) => {
+            config::set_data_dir(data_dir);
+        }
+        None => {
+            if let Err(why) = config::load() {
+                eprintln!("Invalid {}: {}", config::CONFIG_FILE, why);
+                exit(1);
+            }
+        }
+    }
+    let year = selected_year(args.year);
     match args.command {
-        Command::Solve { day } => {
-            run_and_print_day(day);
+        Command::Solve { day, input, args } => {
+            let event = Event::new(year, day);
+            if !is_implemented(event) {
+                eprintln!("{} is not yet implemented\n", event);
+                exit(1);
+            }
+            let input = input.map(|path| match read_input_override(&path) {
+                Ok(input) => input,
+                Err(why) => {
+                    eprintln!("Failed to read {}: {}", path.display(), why);
+                    exit(1);
+                }
+            });
+            let (_, succeeded) = solve_days(vec![event], input, &args);
+            if !succeeded {
+                exit(1);
+            }
+        }
+        Command::SolveMultiple { start, end, args } => {
+            let (_, succeeded) = solve_days(
+                implemented_events(year, Days::bounded(start, end)),
+                None,
+                &args,
+            );
+            if !succeeded {
+                exit(1);
+            }
+        }
+        Command::SolveAll { args, history } => {
+            let (results, succeeded) =
+                solve_days(implemented_events(year, Days::new()), None, &args);
+            if history {
+                record_history(Source::SolveAll, &history::solved_times(&results));
+            }
+            if !succeeded {
+                exit(1);
+            }
         }
-        Command::SolveMultiple { start, end } => {
-            for day in Days::bounded(start, end) {
-                run_and_print_day(day);
+        Command::Compare { day, part } => {
+            if !run_and_print_comparison(Event::new(year, day), part) {
+                exit(1);
+            }
+        }
+        Command::Bench {
+            days,
+            part,
+            save_baseline,
+            baseline,
+            history,
+        } => {
+            let baseline = save_baseline
+                .map(Baseline::Save)
+                .or(baseline.map(Baseline::Compare));
+            let selection = Selection::new(year, &days, part);
+            match bench::cargo_bench(&selection, baseline.as_ref()).status() {
+                Ok(status) if status.success() => {}
+                Ok(status) => exit(status.code().unwrap_or(1)),
+                Err(why) => {
+                    eprintln!("Failed to run cargo bench: {}", why);
+                    exit(1);
+                }
+            }
+            if history {
+                match perf::latest_times(&perf::criterion_dir(), &selection) {
+                    Ok(times) => record_history(Source::Bench, &times),
+                    Err(why) => {
+                        eprintln!("Failed to read the benchmark results: {}", why);
+                        exit(1);
+                    }
+                }
             }
         }
-        Command::SolveAll => {
-            for day in Days::new() {
-                run_and_print_day(day);
+        Command::History { day, part } => {
+            let path = history::history_path();
+            let records = match history::read(&path) {
+                Ok(records) => records,
+                Err(why) => {
+                    eprintln!("Failed to read {}: {}", path.display(), why);
+                    exit(1);
+                }
+            };
+            let event = Event::new(year, day);
+            match history::trend(&records, event, part) {
+                Some(trend) => print!("{}", trend),
+                None => {
+                    eprintln!("No times recorded for {} Part {}", event, part);
+                    exit(1);
+                }
             }
         }
+        Command::PerfCheck {
+            days,
+            part,
+            baseline,
+            threshold,
+            save,
+        } => {
+            let selection = Selection::new(year, &days, part);
+            match bench::cargo_bench(&selection, None).status() {
+                Ok(status) if status.success() => {}
+                Ok(status) => exit(status.code().unwrap_or(1)),
+                Err(why) => {
+                    eprintln!("Failed to run cargo bench: {}", why);
+                    exit(1);
+                }
+            }
+            let criterion_dir = perf::criterion_dir();
+            let comparisons = match perf::compare(&criterion_dir, &baseline, &selection) {
+                Ok(comparisons) => comparisons,
+                Err(why) => {
+                    eprintln!("Failed to read the benchmark results: {}", why);
+                    exit(1);
+                }
+            };
+            if !save
+                && comparisons
+                    .iter()
+                    .all(|comparison| comparison.baseline.is_none())
+            {
+                eprintln!(
+                    "No benchmark has a baseline called {}, run with --save to save one",
+                    baseline
+                );
+                exit(1);
+            }
+            let mut regressions = 0;
+            for comparison in &comparisons {
+                if comparison.regressed(threshold) {
+                    regressions += 1;
+                    println!("{} \x1b[31mREGRESSED\x1b[0m", comparison);
+                } else {
+                    println!("{}", comparison);
+                }
+            }
+            if regressions > 0 {
+                eprintln!(
+                    "{} of {} benchmarks got more than {}% slower than baseline {}",
+                    regressions,
+                    comparisons.len(),
+                    threshold,
+                    baseline
+                );
+                exit(1);
+            }
+            if save {
+                if let Err(why) = perf::save_baseline(&criterion_dir, &baseline, &selection) {
+                    eprintln!("Failed to save baseline {}: {}", baseline, why);
+                    exit(1);
+                }
+                println!("Saved the results as baseline {}", baseline);
+            }
+        }
+        Command::Examples { day } => {
+            if !run_and_print_examples(Event::new(year, day)) {
+                exit(1);
+            }
+        }
+        Command::Verify { day, record } => {
+            let events = match day {
+                Some(day) => vec![Event::new(year, day)],
+                None => implemented_events(year, Days::new()),
+            };
+            let mut all_correct = true;
+            for event in events {
+                all_correct &= run_and_verify_day(event, record);
+            }
+            if !all_correct {
+                exit(1);
+            }
+        }
+        Command::Worker {
+            day,
+            parts,
+            runs,
+            warmup,
+        } => {
+            let config = RunConfig {
+                runs,
+                warmup,
+                timeout: None,
+            };
+            worker::worker_main(Event::new(year, day), &parts, &config);
+        }
         Command::Initialize { day } => {
+            if let Err(why) = initialize_year(year) {
+                eprintln!("Could not initialize {}: {}", year, why);
+                exit(1);
+            }
+
+            let event = Event::new(year, day);
             match File::options()
                 .write(true)
                 .create_new(true)
-                .open(format!("./src/days/day_{}.rs", day).as_str())
+                .open(format!("./src/years/y{}/day_{}.rs", year, day).as_str())
             {
                 Ok(mut file) => {
-                    if let Err(why) = file.write(day_content!(day).as_bytes()) {
-                        eprintln!("Could not initialize day {}: {}", day, why);
+                    if let Err(why) = file.write(day_content!(year, day).as_bytes()) {
+                        eprintln!("Could not initialize {}: {}", event, why);
                         exit(1);
                     }
                 }
                 Err(why) => match why.kind() {
                     ErrorKind::AlreadyExists => {
-                        println!("Day {} is already initialized", day);
+                        println!("{} is already initialized", event);
                         exit(0);
                     }
                     _ => {
-                        eprintln!("Could not initialize day {}: {}", day, why);
+                        eprintln!("Could not initialize {}: {}", event, why);
                         exit(1);
                     }
                 },
             }
 
+            if let Err(why) = create_dir_all(data_path(year, "examples")) {
+                eprintln!("Could not initialize example files for {}. Please remove already generated and now orphaned day file manually. {}", event, why);
+                exit(1);
+            }
+
             match File::options()
                 .write(true)
                 .create_new(true)
-                .open(format!("./data/examples/day_{}_a.txt", day).as_str())
+                .open(data_path(year, "examples").join(format!("day_{}_a.txt", day)))
             {
                 Ok(_) => {}
                 Err(why) => match why.kind() {
                     ErrorKind::AlreadyExists => {
-                        println!("Day {} is already initialized", day);
+                        println!("{} is already initialized", event);
                         exit(0);
                     }
                     _ => {
-                        eprintln!("Could not initialize example file for day {}. Please remove already generated and now orphaned day file manually. {}", day, why);
+                        eprintln!("Could not initialize example file for {}. Please remove already generated and now orphaned day file manually. {}", event, why);
                         exit(1);
                     }
                 },
@@ -144,86 +686,185 @@ fn main() -> AocResult<()> {
             match File::options()
                 .write(true)
                 .create_new(true)
-                .open(format!("./data/examples/day_{}_b.txt", day).as_str())
+                .open(data_path(year, "examples").join(format!("day_{}_b.txt", day)))
             {
                 Ok(_) => {}
                 Err(why) => match why.kind() {
                     ErrorKind::AlreadyExists => {
-                        println!("Day {} is already initialized", day);
+                        println!("{} is already initialized", event);
                         exit(0);
                     }
                     _ => {
-                        eprintln!("Could not initialize example file for day {}. Please remove already generated and now orphaned day file manually. {}", day, why);
+                        eprintln!("Could not initialize example file for {}. Please remove already generated and now orphaned day file manually. {}", event, why);
                         exit(1);
                     }
                 },
             }
 
-            match File::options().append(true).open("./src/days.rs") {
+            match File::options()
+                .append(true)
+                .open(format!("./src/years/y{}.rs", year))
+            {
                 Ok(mut file) => {
                     if let Err(why) = file.write(format!("\npub mod day_{};", day).as_bytes()) {
                         eprintln!(
-                            "Could not add module for Day {}. Please remove already generated and now orphaned day & example files manually. {}",
-                            day, why
+                            "Could not add module for {}. Please remove already generated and now orphaned day & example files manually. {}",
+                            event, why
                         );
                         exit(1);
                     }
-                    println!("Initialized Day {}", day);
+                    println!("Initialized {}", event);
                 }
                 Err(why) => {
                     eprintln!(
-                        "Could not add module for Day {}. Please remove already generated and now orphaned day & example files manually. {}",
-                        day, why
+                        "Could not add module for {}. Please remove already generated and now orphaned day & example files manually. {}",
+                        event, why
                     );
                     exit(1);
                 }
             }
         }
-        Command::Download { day } => {
-            let client = AocClient::builder()
-                .session_cookie_from_default_locations()?
-                .year(std::env::var("AOC_YEAR").unwrap().parse().unwrap())?
-                .day((usize::from(day) + 1) as PuzzleDay)?
-                .build()?;
-            let input = client.get_input()?;
-
-            match File::options()
-                .write(true)
-                .create_new(true)
-                .open(format!("./data/inputs/day_{}.txt", day).as_str())
-            {
-                Ok(mut file) => {
-                    if let Err(why) = file.write(input.as_bytes()) {
-                        eprintln!("Could not download Day {}: {}", day, why);
+        Command::Download { day, all, base_url } => {
+            let events = match day {
+                Some(day) if !all => vec![Event::new(year, day)],
+                _ => Days::new().map(|day| Event::new(year, day)).collect(),
+            };
+            let client = match Client::from_config(base_url) {
+                Ok(client) => client,
+                Err(why) => {
+                    eprintln!("Could not download {}: {}", events[0], why);
+                    exit(1);
+                }
+            };
+            for event in events {
+                match download_input(&client, event, Utc::now()) {
+                    Ok(Download::Cached) => println!("{} is already downloaded", event),
+                    Ok(Download::Fetched(_)) => println!("Downloaded {}", event),
+                    Ok(Download::Locked { unlocks_at }) => {
+                        println!("{} unlocks at {}", event, unlocks_at);
+                        break;
+                    }
+                    Err(why) => {
+                        eprintln!("Could not download {}: {}", event, why);
                         exit(1);
                     }
-                    println!("Downloaded Day {}", day);
                 }
-                Err(why) => match why.kind() {
-                    ErrorKind::AlreadyExists => {
-                        println!("Day {} is already downloaded", day);
-                        exit(0);
-                    }
-                    _ => {
-                        eprintln!("Could not download day {}: {}", day, why);
+            }
+        }
+        Command::Submit {
+            day,
+            part,
+            base_url,
+        } => {
+            let event = Event::new(year, day);
+            let input = match read_inputs(event) {
+                Ok(inputs) => inputs.into_iter().find(|input| input.name == DEFAULT_INPUT),
+                Err(why) => {
+                    eprintln!("Failed to get input for {}: {}", event, why);
+                    exit(1);
+                }
+            };
+            let Some(input) = input else {
+                eprintln!(
+                    "There is no puzzle input at {}",
+                    input_path(event).display()
+                );
+                exit(1);
+            };
+            let results = run_day_parts(event, &[part], &input, &RunConfig::default());
+            let Some(result) = results.into_iter().next() else {
+                eprintln!("{} Part {} is not yet implemented", event, part);
+                exit(1);
+            };
+            let Some(answer) = result.answer else {
+                eprintln!(
+                    "{} Part {}: Nothing to submit, {}",
+                    event,
+                    part,
+                    result.error.unwrap_or(result.status.to_string())
+                );
+                exit(1);
+            };
+            if result.expected.as_ref() == Some(&answer) {
+                println!(
+                    "{} Part {}: {} is already known to be correct",
+                    event, part, answer
+                );
+                return Ok(());
+            }
+
+            let client = Client::from_config(base_url);
+            let submission =
+                client.and_then(|client| submit::submit_answer(&client, event, part, &answer));
+            match submission {
+                Ok(submit::Submission::Refused(refusal)) => {
+                    println!(
+                        "{} Part {}: Not submitting {}, {}",
+                        event, part, answer, refusal
+                    );
+                    exit(1);
+                }
+                Ok(submit::Submission::Sent(outcome)) => {
+                    println!("{} Part {}: {} [{}]", event, part, answer, outcome);
+                    if outcome != submit::Outcome::Correct {
                         exit(1);
                     }
-                },
+                }
+                Err(why) => {
+                    eprintln!("Failed to submit {} Part {}: {}", event, part, why);
+                    exit(1);
+                }
             }
         }
         Command::Standalone { day, with_tests } => {
-            let path = format!("src/days/day_{}.rs", day);
+            let input_path = input_path(Event::new(year, day)).display().to_string();
+            let path = format!("src/years/y{}/day_{}.rs", year, day);
             let mut src = match std::fs::read_to_string(&path) {
                 Err(e) => {
                     eprintln!("Failed to read {}: {}", path, e);
                     exit(1)
                 }
-                Ok(content) => content,
+                Ok(content) => content
+                    .lines()
+                    .filter(|line| {
+                        !line.trim_start().starts_with("#[aoc(")
+                            && line.trim() != "use aoc_macros::aoc;"
+                            && !line.starts_with("use crate::")
+                    })
+                    .map(|line| format!("{}\n", line))
+                    .collect::<String>(),
             };
-            if !with_tests {
-                src.replace_range(src.find("#[cfg(test)]").unwrap().., "");
+            // The day's own tests use the library, with `--with-tests` the
+            // example tests take their place.
+            if let Some(tests_start) = src.find("#[cfg(test)]") {
+                src.replace_range(tests_start.., "");
+            }
+            let solution = src.find("impl Solution for ").map(|start| {
+                src[start..]
+                    .trim_start_matches("impl Solution for ")
+                    .split(|c: char| !c.is_alphanumeric() && c != '_')
+                    .next()
+                    .unwrap()
+                    .to_string()
+            });
+            if with_tests {
+                let tests = example_tests(Event::new(year, day), solution.as_deref());
+                src = format!("{}\n\n{}", src.trim_end(), tests);
+            }
+            if src.contains("AocError") {
+                src = format!("{}\n\n{}", AOC_ERROR, src);
+            }
+            match solution {
+                None => println!("{}\n\n{}", main_fn!(input_path), src.trim_end()),
+                Some(solution) => {
+                    let src = format!("{}\n\n{}", SOLUTION_TRAIT, src);
+                    println!(
+                        "{}\n\n{}",
+                        solution_main_fn!(input_path, solution),
+                        src.trim_end()
+                    );
+                }
             }
-            println!("{}\n\n{}", main_fn!(day), src.trim_end());
         }
     }
 
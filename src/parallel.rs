@@ -0,0 +1,112 @@
+use crate::{read_inputs, run_day, run_day_parts, Event, Part, PartResult, RunConfig, Status};
+use std::collections::BTreeMap;
+use std::io;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+pub type DayResult = io::Result<Vec<PartResult>>;
+
+/// Runs `events` on up to `jobs` threads and hands each day's results to
+/// `on_day` in the order of `events`, as soon as all earlier days are done.
+fn run_days_parallel(
+    events: &[Event],
+    config: &RunConfig,
+    jobs: usize,
+    mut on_day: impl FnMut(Event, DayResult),
+) {
+    let next_day = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, events.len().max(1)) {
+            let sender = sender.clone();
+            let next_day = &next_day;
+            scope.spawn(move || loop {
+                let index = next_day.fetch_add(1, Ordering::Relaxed);
+                let Some(&event) = events.get(index) else {
+                    break;
+                };
+                if sender.send((index, run_day(event, config))).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+
+        let mut pending = BTreeMap::new();
+        let mut next_index = 0;
+        for (index, result) in receiver {
+            pending.insert(index, result);
+            while let Some(result) = pending.remove(&next_index) {
+                on_day(events[next_index], result);
+                next_index += 1;
+            }
+        }
+    });
+}
+
+/// Times the parts of a day again, those of the same input together so that
+/// they share their parse step. Keeps the results of parts that already
+/// failed during the correctness pass.
+fn retime_day(event: Event, results: Vec<PartResult>, config: &RunConfig) -> DayResult {
+    let mut retimed = vec![];
+    for input in read_inputs(event)? {
+        let parts: Vec<Part> = results
+            .iter()
+            .filter(|result| {
+                result.input == input.name
+                    && matches!(result.status, Status::Solved | Status::Unsolved)
+            })
+            .map(|result| result.part)
+            .collect();
+        if !parts.is_empty() {
+            retimed.extend(run_day_parts(event, &parts, &input, config));
+        }
+    }
+    Ok(results
+        .into_iter()
+        .map(|result| {
+            let index = retimed
+                .iter()
+                .position(|retimed| retimed.input == result.input && retimed.part == result.part);
+            match index {
+                Some(index) => retimed.swap_remove(index),
+                None => result,
+            }
+        })
+        .collect())
+}
+
+/// Runs every day in `events` and reports their results in order.
+///
+/// With `jobs > 1`, days are solved concurrently, which skews their timings.
+/// `isolated` avoids that by only checking answers in parallel and then
+/// timing every part again with nothing else running.
+pub fn run_days(
+    events: &[Event],
+    config: &RunConfig,
+    jobs: usize,
+    isolated: bool,
+    mut on_day: impl FnMut(Event, DayResult),
+) {
+    if !isolated || jobs <= 1 {
+        return run_days_parallel(events, config, jobs, on_day);
+    }
+
+    let correctness_config = RunConfig {
+        runs: 1,
+        warmup: 0,
+        ..*config
+    };
+    let mut checked = Vec::with_capacity(events.len());
+    run_days_parallel(events, &correctness_config, jobs, |event, result| {
+        checked.push((event, result))
+    });
+    for (event, result) in checked {
+        on_day(
+            event,
+            result.and_then(|results| retime_day(event, results, config)),
+        );
+    }
+}
@@ -0,0 +1,353 @@
+use crate::bench::Selection;
+use crate::registry::{registrations, variants};
+use crate::{config, Event, Part, PartSolver, DEFAULT_INPUT};
+use humantime::format_duration;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::fs::{copy, create_dir_all, read_dir, read_to_string};
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Criterion's name for the results of the latest run of a benchmark.
+const LATEST_RUN: &str = "new";
+
+/// Where criterion keeps its results.
+pub fn criterion_dir() -> PathBuf {
+    config::target_dir().join("criterion")
+}
+
+#[derive(Deserialize)]
+struct BenchmarkId {
+    group_id: String,
+    function_id: Option<String>,
+    /// The name of the input.
+    value_str: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Estimates {
+    mean: Estimate,
+}
+
+#[derive(Deserialize)]
+struct Estimate {
+    /// In nanoseconds.
+    point_estimate: f64,
+}
+
+fn invalid_data(why: serde_json::Error) -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, why)
+}
+
+/// The mean time of a run saved in `run_dir`, if there is one.
+fn mean_time(run_dir: &Path) -> io::Result<Option<Duration>> {
+    match read_to_string(run_dir.join("estimates.json")) {
+        Ok(content) => {
+            let estimates: Estimates = serde_json::from_str(&content).map_err(invalid_data)?;
+            Ok(Some(Duration::from_secs_f64(
+                estimates.mean.point_estimate / 1e9,
+            )))
+        }
+        Err(why) if why.kind() == ErrorKind::NotFound => Ok(None),
+        Err(why) => Err(why),
+    }
+}
+
+/// The event, part and variant of a benchmark named like
+/// `<year> Day <day>/Part <part> | <variant> | Solve/<input>`, or
+/// `<year> Day <day>/Parse | <variant>/<input>` without a part.
+fn benchmark_of(id: &BenchmarkId) -> Option<(Event, Option<Part>, String)> {
+    let (year, day) = id.group_id.split_once(" Day ")?;
+    let event = Event::new(year.parse().ok()?, day.parse().ok()?);
+    let function_id = id.function_id.as_deref()?;
+    if let Some(variant) = function_id.strip_prefix("Parse | ") {
+        return Some((event, None, variant.to_string()));
+    }
+    let mut steps = function_id.strip_prefix("Part ")?.split(" | ");
+    let part = steps.next()?.parse().ok()?;
+    let variant = steps.next()?;
+    Some((event, Some(part), variant.to_string()))
+}
+
+struct Benchmark {
+    dir: PathBuf,
+    event: Event,
+    /// `None` for parsing the input.
+    part: Option<Part>,
+    variant: String,
+    input: String,
+}
+
+fn find_benchmark_dirs(dir: &Path, found: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in read_dir(dir)? {
+        let path = entry?.path();
+        if !path.is_dir() {
+            continue;
+        }
+        if path.ends_with(LATEST_RUN) && path.join("benchmark.json").is_file() {
+            found.push(dir.to_path_buf());
+        } else {
+            find_benchmark_dirs(&path, found)?;
+        }
+    }
+    Ok(())
+}
+
+/// The benchmarks of puzzle parts in `selection` that ran at least once,
+/// along with the parse benchmarks of their days.
+fn benchmarks(criterion_dir: &Path, selection: &Selection) -> io::Result<Vec<Benchmark>> {
+    let mut dirs = vec![];
+    if criterion_dir.is_dir() {
+        find_benchmark_dirs(criterion_dir, &mut dirs)?;
+    }
+    let mut benchmarks = vec![];
+    for dir in dirs {
+        let content = read_to_string(dir.join(LATEST_RUN).join("benchmark.json"))?;
+        let id: BenchmarkId = serde_json::from_str(&content).map_err(invalid_data)?;
+        let Some((event, part, variant)) = benchmark_of(&id) else {
+            continue;
+        };
+        let input = id.value_str.unwrap_or_default();
+        let selected = match part {
+            Some(part) => selection.includes_part(event, part),
+            None => selection.includes(event),
+        };
+        if selected {
+            benchmarks.push(Benchmark {
+                dir,
+                event,
+                part,
+                variant,
+                input,
+            });
+        }
+    }
+    Ok(benchmarks)
+}
+
+/// The time of a variant of a part, or of parsing a day's input, on one input
+/// in the latest run and in a baseline.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub event: Event,
+    /// `None` for parsing the input.
+    pub part: Option<Part>,
+    pub variant: String,
+    pub input: String,
+    /// `None` if the benchmark is missing from the baseline.
+    pub baseline: Option<Duration>,
+    pub latest: Duration,
+}
+
+impl Comparison {
+    /// How much slower the latest run is than the baseline, in percent.
+    pub fn change(&self) -> Option<f64> {
+        self.baseline
+            .map(|baseline| (self.latest.as_secs_f64() / baseline.as_secs_f64() - 1.0) * 100.0)
+    }
+
+    pub fn regressed(&self, threshold: f64) -> bool {
+        self.change().is_some_and(|change| change > threshold)
+    }
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.part {
+            Some(part) => write!(f, "{} Part {}", self.event, part)?,
+            None => write!(f, "{} Parse", self.event)?,
+        }
+        write!(f, " | {} | {}: ", self.variant, self.input)?;
+        match (self.baseline, self.change()) {
+            (Some(baseline), Some(change)) => write!(
+                f,
+                "{} -> {} ({:+.1}%)",
+                format_duration(baseline),
+                format_duration(self.latest),
+                change
+            ),
+            _ => write!(f, "{} (no baseline)", format_duration(self.latest)),
+        }
+    }
+}
+
+/// Compares the latest run of every benchmark in `selection` with the
+/// baseline called `baseline`, one variant and input at a time and with
+/// parsing on its own.
+pub fn compare(
+    criterion_dir: &Path,
+    baseline: &str,
+    selection: &Selection,
+) -> io::Result<Vec<Comparison>> {
+    let mut comparisons = vec![];
+    for benchmark in benchmarks(criterion_dir, selection)? {
+        let Some(latest) = mean_time(&benchmark.dir.join(LATEST_RUN))? else {
+            continue;
+        };
+        comparisons.push(Comparison {
+            event: benchmark.event,
+            part: benchmark.part,
+            variant: benchmark.variant,
+            input: benchmark.input,
+            baseline: mean_time(&benchmark.dir.join(baseline))?,
+            latest,
+        });
+    }
+    comparisons.sort_by(|a, b| {
+        (a.event, a.part, &a.variant, &a.input).cmp(&(b.event, b.part, &b.variant, &b.input))
+    });
+    Ok(comparisons)
+}
+
+/// The variant that the bench target names the parse benchmark of a part's
+/// solver after: the first one benchmarked that parses the same way.
+fn parse_benchmark_variant(
+    event: Event,
+    solver: &dyn PartSolver,
+    selection: &Selection,
+) -> Option<&'static str> {
+    let parser = solver.parser()?;
+    registrations()
+        .into_iter()
+        .filter(|registration| registration.event == event)
+        .filter(|registration| selection.part.is_none_or(|part| part == registration.part))
+        .find(|registration| registration.solver.parser() == Some(parser))
+        .map(|registration| registration.variant)
+}
+
+/// The time of every part in `selection` in the latest run, of the variant
+/// `solve` uses on the puzzle input. Like the time of a part in `solve-all`,
+/// it includes parsing the input.
+pub fn latest_times(
+    criterion_dir: &Path,
+    selection: &Selection,
+) -> io::Result<Vec<(Event, Part, Duration)>> {
+    let mut times: BTreeMap<(Event, Option<Part>, String), Duration> = BTreeMap::new();
+    for benchmark in benchmarks(criterion_dir, selection)? {
+        if benchmark.input != DEFAULT_INPUT {
+            continue;
+        }
+        if let Some(latest) = mean_time(&benchmark.dir.join(LATEST_RUN))? {
+            times.insert((benchmark.event, benchmark.part, benchmark.variant), latest);
+        }
+    }
+    let mut part_times = vec![];
+    for (&(event, part, ref variant), &solve_time) in &times {
+        let Some(part) = part else {
+            continue;
+        };
+        let Some(solver) = variants(event, part).first().copied() else {
+            continue;
+        };
+        if solver.variant != variant {
+            continue;
+        }
+        let parse_time = match parse_benchmark_variant(event, solver.solver, selection) {
+            Some(parse_variant) => match times.get(&(event, None, parse_variant.to_string())) {
+                Some(&parse_time) => parse_time,
+                None => continue,
+            },
+            None => Duration::ZERO,
+        };
+        part_times.push((event, part, parse_time + solve_time));
+    }
+    Ok(part_times)
+}
+
+/// Keeps the latest run of every benchmark in `selection` as the baseline
+/// called `baseline`, like `--save-baseline` would have.
+pub fn save_baseline(
+    criterion_dir: &Path,
+    baseline: &str,
+    selection: &Selection,
+) -> io::Result<()> {
+    for benchmark in benchmarks(criterion_dir, selection)? {
+        let baseline_dir = benchmark.dir.join(baseline);
+        create_dir_all(&baseline_dir)?;
+        for entry in read_dir(benchmark.dir.join(LATEST_RUN))? {
+            let entry = entry?;
+            copy(entry.path(), baseline_dir.join(entry.file_name()))?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::fs::{remove_dir_all, write};
+
+    fn write_run(dir: &Path, run: &str, function_id: &str, nanos: f64) {
+        let run_dir = dir
+            .join(function_id.replace('|', "_"))
+            .join("default")
+            .join(run);
+        create_dir_all(&run_dir).unwrap();
+        let id = serde_json::json!({
+            "group_id": "2023 Day 05",
+            "function_id": function_id,
+            "value_str": "default",
+        });
+        write(run_dir.join("benchmark.json"), id.to_string()).unwrap();
+        let estimates = serde_json::json!({ "mean": { "point_estimate": nanos } });
+        write(run_dir.join("estimates.json"), estimates.to_string()).unwrap();
+    }
+
+    #[test]
+    fn test_compare_each_benchmark() {
+        let criterion_dir = env::temp_dir().join(format!("aoc-perf-{}", std::process::id()));
+        let day_05 = criterion_dir.join("2023 Day 05");
+        write_run(&day_05, "new", "Parse | default", 300.0);
+        write_run(&day_05, "new", "Part A | default | Solve", 900.0);
+        write_run(&day_05, "main", "Parse | default", 200.0);
+        write_run(&day_05, "main", "Part A | default | Solve", 800.0);
+        write_run(&day_05, "new", "Part B | default | Solve", 500.0);
+        write_run(&day_05, "new", "Part B | bruteforce | Solve", 4000.0);
+
+        let all = Selection::default();
+        let comparisons = compare(&criterion_dir, "main", &all).unwrap();
+        assert_eq!(comparisons.len(), 4);
+        assert_eq!(comparisons[0].part, None);
+        assert_eq!(comparisons[0].input, "default");
+        assert_eq!(comparisons[0].latest, Duration::from_nanos(300));
+        assert_eq!(comparisons[0].baseline, Some(Duration::from_nanos(200)));
+        assert!(comparisons[0].regressed(10.0));
+        assert_eq!(comparisons[1].part, Some(Part::A));
+        assert_eq!(comparisons[1].latest, Duration::from_nanos(900));
+        assert!(comparisons[1].regressed(10.0));
+        assert!(!comparisons[1].regressed(25.0));
+        assert_eq!(comparisons[2].variant, "bruteforce");
+        assert_eq!(comparisons[2].latest, Duration::from_nanos(4000));
+        assert_eq!(comparisons[2].baseline, None);
+        assert!(!comparisons[2].regressed(0.0));
+        assert_eq!(comparisons[3].variant, "default");
+        assert_eq!(comparisons[3].latest, Duration::from_nanos(500));
+        assert_eq!(
+            comparisons[3].to_string(),
+            "2023 Day 05 Part B | default | default: 500ns (no baseline)"
+        );
+
+        let times = latest_times(&criterion_dir, &all).unwrap();
+        assert_eq!(times.len(), 2);
+        assert_eq!(times[0].1, Part::A);
+        assert_eq!(times[0].2, Duration::from_nanos(1200));
+        assert_eq!(times[1].1, Part::B);
+        assert_eq!(times[1].2, Duration::from_nanos(800));
+
+        let part_b = Selection {
+            part: Some(Part::B),
+            ..Selection::default()
+        };
+        save_baseline(&criterion_dir, "main", &part_b).unwrap();
+        let comparisons = compare(&criterion_dir, "main", &all).unwrap();
+        assert_eq!(comparisons[0].change(), Some(0.0));
+        assert_eq!(comparisons[1].baseline, Some(Duration::from_nanos(800)));
+        assert_eq!(comparisons[2].change(), Some(0.0));
+        assert_eq!(comparisons[3].change(), Some(0.0));
+
+        remove_dir_all(criterion_dir).unwrap();
+    }
+}
@@ -0,0 +1,119 @@
+use crate::{Event, Part, PartSolver, Year};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+pub const DEFAULT_VARIANT: &str = "default";
+
+/// A solver registered for one `(Event, Part)` through `#[aoc(...)]`.
+pub struct Registration {
+    pub event: Event,
+    pub part: Part,
+    pub variant: &'static str,
+    pub solver: &'static dyn PartSolver,
+}
+
+inventory::collect!(Registration);
+
+impl Display for Registration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} | Part {}", self.event, self.part)?;
+        if self.variant != DEFAULT_VARIANT {
+            write!(f, " | {}", self.variant)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct NotImplementedError {
+    event: Event,
+    part: Part,
+}
+
+impl Display for NotImplementedError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} Part {} is not yet implemented",
+            self.event, self.part
+        )
+    }
+}
+
+impl NotImplementedError {
+    pub fn new(event: Event, part: Part) -> Self {
+        Self { event, part }
+    }
+}
+
+impl Error for NotImplementedError {}
+
+/// All registered solvers ordered by year, day and part, default variants first.
+pub fn registrations() -> Vec<&'static Registration> {
+    let mut registrations: Vec<_> = inventory::iter::<Registration>.into_iter().collect();
+    registrations.sort_by_key(|registration| {
+        (
+            registration.event,
+            registration.part,
+            registration.variant != DEFAULT_VARIANT,
+            registration.variant,
+        )
+    });
+    registrations
+}
+
+/// Every `(Event, Part)` that has at least one registered solver.
+pub fn registered_parts() -> Vec<(Event, Part)> {
+    let mut parts: Vec<_> = registrations()
+        .into_iter()
+        .map(|registration| (registration.event, registration.part))
+        .collect();
+    parts.dedup();
+    parts
+}
+
+/// Every `Event` that has at least one registered solver.
+pub fn registered_events() -> Vec<Event> {
+    let mut events: Vec<_> = registrations()
+        .into_iter()
+        .map(|registration| registration.event)
+        .collect();
+    events.dedup();
+    events
+}
+
+/// Every registered variant of a single part.
+pub fn variants(event: Event, part: Part) -> Vec<&'static Registration> {
+    registrations()
+        .into_iter()
+        .filter(|registration| registration.event == event && registration.part == part)
+        .collect()
+}
+
+/// The solver used for a part when no variant is asked for: the default
+/// variant if there is one, otherwise the first variant by name.
+pub fn get_solver(
+    event: Event,
+    part: Part,
+) -> Result<&'static dyn PartSolver, NotImplementedError> {
+    variants(event, part)
+        .first()
+        .map(|registration| registration.solver)
+        .ok_or(NotImplementedError::new(event, part))
+}
+
+pub fn is_implemented(event: Event) -> bool {
+    inventory::iter::<Registration>
+        .into_iter()
+        .any(|registration| registration.event == event)
+}
+
+/// Every year with at least one registered solver, oldest first.
+pub fn registered_years() -> Vec<Year> {
+    let mut years: Vec<_> = registrations()
+        .into_iter()
+        .map(|registration| registration.event.year)
+        .collect();
+    years.dedup();
+    years
+}
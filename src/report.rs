@@ -0,0 +1,652 @@
+use crate::alloc::format_bytes;
+use crate::answers::Verdict;
+use crate::stats::TimingStats;
+use crate::{AllocStats, Answer, Event, Part};
+use humantime::format_duration;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::time::Duration;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Solved,
+    Unsolved,
+    /// The solver rejected the input with an `AocError`.
+    InputError,
+    Panicked,
+    TimedOut,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string_repr = match self {
+            Status::Solved => "solved",
+            Status::Unsolved => "unsolved",
+            Status::InputError => "input_error",
+            Status::Panicked => "panicked",
+            Status::TimedOut => "timed_out",
+        };
+        write!(f, "{}", string_repr)
+    }
+}
+
+/// The outcome of running one `(Event, Part)` on one input. With repeated
+/// runs, the parse and solve times are medians and `stats` holds the full
+/// statistics.
+#[derive(Debug, Clone)]
+pub struct PartResult {
+    pub event: Event,
+    /// The name of the input the part was run on.
+    pub input: String,
+    pub part: Part,
+    pub answer: Option<Answer>,
+    /// The answer recorded for this input, if there is one.
+    pub expected: Option<Answer>,
+    pub status: Status,
+    /// Why a part failed, e.g. the message and location of a panic.
+    pub error: Option<String>,
+    /// `None` for parts that solve the raw input. Parts that share a parse
+    /// step both report its time.
+    pub parse_time: Option<Duration>,
+    /// Whether an earlier part already reported this parse step on the same
+    /// input, so that totals count it only once.
+    pub shared_parse: bool,
+    pub solve_time: Duration,
+    pub stats: Option<TimingStats>,
+    /// The heap use of the first run, with the `alloc-stats` feature.
+    pub allocations: Option<AllocStats>,
+    /// How many of `allocations` were made by the parse step.
+    pub parse_allocations: Option<u64>,
+}
+
+impl PartResult {
+    pub fn elapsed(&self) -> Duration {
+        self.parse_time.unwrap_or_default() + self.solve_time
+    }
+
+    /// The time the part adds to a run of its day, without a parse step that
+    /// an earlier part already reported.
+    pub fn counted_time(&self) -> Duration {
+        if self.shared_parse {
+            self.solve_time
+        } else {
+            self.elapsed()
+        }
+    }
+
+    /// The allocations the part adds to a run of its day, without the ones of
+    /// a parse step that an earlier part already reported.
+    pub fn counted_allocations(&self) -> Option<u64> {
+        let allocations = self.allocations?.allocations;
+        match self.parse_allocations {
+            Some(parse_allocations) if self.shared_parse => Some(allocations - parse_allocations),
+            _ => Some(allocations),
+        }
+    }
+
+    pub fn verdict(&self) -> Verdict {
+        Verdict::new(self.answer.as_ref(), self.expected.as_ref())
+    }
+
+    /// Whether the part panicked, timed out, rejected its input or disagreed
+    /// with its recorded answer.
+    pub fn failed(&self) -> bool {
+        matches!(
+            self.status,
+            Status::InputError | Status::Panicked | Status::TimedOut
+        ) || matches!(self.verdict(), Verdict::Wrong { .. })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct Record {
+    year: String,
+    day: String,
+    input: String,
+    part: String,
+    answer: Option<Answer>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    expected: Option<Answer>,
+    status: Status,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    elapsed_ns: u128,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    parse_ns: Option<u128>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    shared_parse: bool,
+    solve_ns: u128,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    runs: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    iterations: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_ns: Option<u128>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    median_ns: Option<u128>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mean_ns: Option<u128>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    std_dev_ns: Option<u128>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    allocations: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    allocated_bytes: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    peak_bytes: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    parse_allocations: Option<u64>,
+}
+
+impl From<&PartResult> for Record {
+    fn from(result: &PartResult) -> Self {
+        Record {
+            year: result.event.year.to_string(),
+            day: result.event.day.to_string(),
+            input: result.input.clone(),
+            part: result.part.to_string(),
+            answer: result.answer.clone(),
+            expected: result.expected.clone(),
+            status: result.status,
+            error: result.error.clone(),
+            elapsed_ns: result.elapsed().as_nanos(),
+            parse_ns: result.parse_time.map(|parse_time| parse_time.as_nanos()),
+            shared_parse: result.shared_parse,
+            solve_ns: result.solve_time.as_nanos(),
+            runs: result.stats.map(|stats| stats.runs),
+            iterations: result.stats.map(|stats| stats.iterations),
+            min_ns: result.stats.map(|stats| stats.min.as_nanos()),
+            median_ns: result.stats.map(|stats| stats.median.as_nanos()),
+            mean_ns: result.stats.map(|stats| stats.mean.as_nanos()),
+            std_dev_ns: result.stats.map(|stats| stats.std_dev.as_nanos()),
+            allocations: result
+                .allocations
+                .map(|allocations| allocations.allocations),
+            allocated_bytes: result.allocations.map(|allocations| allocations.bytes),
+            peak_bytes: result.allocations.map(|allocations| allocations.peak_bytes),
+            parse_allocations: result.parse_allocations,
+        }
+    }
+}
+
+impl Record {
+    fn into_part_result(self) -> Option<PartResult> {
+        let stats = match (self.runs, self.iterations) {
+            (Some(runs), Some(iterations)) => Some(TimingStats {
+                min: Duration::from_nanos(self.min_ns? as u64),
+                median: Duration::from_nanos(self.median_ns? as u64),
+                mean: Duration::from_nanos(self.mean_ns? as u64),
+                std_dev: Duration::from_nanos(self.std_dev_ns? as u64),
+                runs,
+                iterations,
+            }),
+            _ => None,
+        };
+        let allocations = match (self.allocations, self.allocated_bytes, self.peak_bytes) {
+            (Some(allocations), Some(bytes), Some(peak_bytes)) => Some(AllocStats {
+                allocations,
+                bytes,
+                peak_bytes,
+                retained_bytes: 0,
+            }),
+            _ => None,
+        };
+        Some(PartResult {
+            event: Event::new(self.year.parse().ok()?, self.day.parse().ok()?),
+            input: self.input,
+            part: self.part.parse().ok()?,
+            answer: self.answer,
+            expected: self.expected,
+            status: self.status,
+            error: self.error,
+            parse_time: self
+                .parse_ns
+                .map(|parse_ns| Duration::from_nanos(parse_ns as u64)),
+            shared_parse: self.shared_parse,
+            solve_time: Duration::from_nanos(self.solve_ns as u64),
+            stats,
+            allocations,
+            parse_allocations: self.parse_allocations,
+        })
+    }
+}
+
+/// A single result as one line of JSON, as passed from worker processes.
+pub fn to_json_line(result: &PartResult) -> String {
+    serde_json::to_string(&Record::from(result)).unwrap()
+}
+
+pub fn from_json_line(line: &str) -> Option<PartResult> {
+    serde_json::from_str::<Record>(line)
+        .ok()?
+        .into_part_result()
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum OutputFormat {
+    #[default]
+    Human,
+    Json,
+    Csv,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct ParseOutputFormatError {}
+impl Display for ParseOutputFormatError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Expected one of human, json or csv")
+    }
+}
+impl Default for ParseOutputFormatError {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParseOutputFormatError {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Error for ParseOutputFormatError {}
+
+impl FromStr for OutputFormat {
+    type Err = ParseOutputFormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "human" => Ok(OutputFormat::Human),
+            "json" => Ok(OutputFormat::Json),
+            "csv" => Ok(OutputFormat::Csv),
+            _ => Err(ParseOutputFormatError::new()),
+        }
+    }
+}
+
+pub fn to_json(results: &[PartResult]) -> String {
+    let records: Vec<Record> = results.iter().map(Record::from).collect();
+    serde_json::to_string_pretty(&records).unwrap()
+}
+
+fn optional_field<T: ToString>(field: Option<T>) -> String {
+    field.map(|field| field.to_string()).unwrap_or_default()
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+pub fn to_csv(results: &[PartResult]) -> String {
+    let mut csv = String::from(
+        "year,day,input,part,answer,expected,status,error,elapsed_ns,parse_ns,shared_parse,solve_ns,runs,iterations,min_ns,median_ns,mean_ns,std_dev_ns,allocations,allocated_bytes,peak_bytes,parse_allocations\n",
+    );
+    for record in results.iter().map(Record::from) {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}\n",
+            record.year,
+            record.day,
+            csv_field(&record.input),
+            record.part,
+            csv_field(&optional_field(record.answer)),
+            csv_field(&optional_field(record.expected)),
+            record.status,
+            csv_field(&record.error.unwrap_or_default()),
+            record.elapsed_ns,
+            optional_field(record.parse_ns),
+            record.shared_parse,
+            record.solve_ns,
+            optional_field(record.runs),
+            optional_field(record.iterations),
+            optional_field(record.min_ns),
+            optional_field(record.median_ns),
+            optional_field(record.mean_ns),
+            optional_field(record.std_dev_ns),
+            optional_field(record.allocations),
+            optional_field(record.allocated_bytes),
+            optional_field(record.peak_bytes),
+            optional_field(record.parse_allocations),
+        ));
+    }
+    csv
+}
+
+/// How many of the most expensive parts the summary table points out.
+const HIGHLIGHT_SLOWEST: usize = 3;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum SortOrder {
+    #[default]
+    Day,
+    Time,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct ParseSortOrderError {}
+impl Display for ParseSortOrderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Expected one of day or time")
+    }
+}
+impl Default for ParseSortOrderError {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParseSortOrderError {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Error for ParseSortOrderError {}
+
+impl FromStr for SortOrder {
+    type Err = ParseSortOrderError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "day" => Ok(SortOrder::Day),
+            "time" => Ok(SortOrder::Time),
+            _ => Err(ParseSortOrderError::new()),
+        }
+    }
+}
+
+/// A table of every part with its answer, status, time and share of the total
+/// runtime. The slowest parts are marked, and with `bold` also emphasized. A
+/// parse step shared by several parts counts towards the total and share of
+/// the first one only.
+pub fn summary_table(results: &[PartResult], sort: SortOrder, bold: bool) -> String {
+    let total: Duration = results.iter().map(PartResult::counted_time).sum();
+
+    let mut by_time: Vec<&PartResult> = results.iter().collect();
+    by_time.sort_by_key(|result| std::cmp::Reverse(result.elapsed()));
+    let slowest: Vec<(Event, &str, Part)> = by_time
+        .iter()
+        .take(HIGHLIGHT_SLOWEST.min(results.len().saturating_sub(1)))
+        .map(|result| (result.event, result.input.as_str(), result.part))
+        .collect();
+
+    let ordered = match sort {
+        SortOrder::Day => results.iter().collect(),
+        SortOrder::Time => by_time,
+    };
+
+    // Only builds with the `alloc-stats` feature count allocations.
+    let with_allocations = results.iter().any(|result| result.allocations.is_some());
+
+    let mut rows: Vec<Vec<String>> = ordered
+        .iter()
+        .map(|result| {
+            let answer = match &result.answer {
+                Some(answer) if answer.is_multi_line() => "(multi-line)".to_string(),
+                Some(answer) => answer.to_string(),
+                None => "-".to_string(),
+            };
+            let share = if total.is_zero() {
+                0.0
+            } else {
+                100.0 * result.counted_time().as_secs_f64() / total.as_secs_f64()
+            };
+            let mut row = vec![
+                result.event.year.to_string(),
+                result.event.day.to_string(),
+                result.input.clone(),
+                result.part.to_string(),
+                answer,
+                result.status.to_string(),
+                format_duration(result.elapsed()).to_string(),
+                format!("{:.1}%", share),
+            ];
+            if with_allocations {
+                match result.allocations {
+                    Some(allocations) => {
+                        row.push(allocations.allocations.to_string());
+                        row.push(format_bytes(allocations.peak_bytes));
+                    }
+                    None => row.extend(["-".to_string(), "-".to_string()]),
+                }
+            }
+            row
+        })
+        .collect();
+    let mut total_row = vec![
+        "Total".to_string(),
+        String::new(),
+        String::new(),
+        String::new(),
+        String::new(),
+        String::new(),
+        format_duration(total).to_string(),
+        "100.0%".to_string(),
+    ];
+    if with_allocations {
+        total_row.push(
+            results
+                .iter()
+                .filter_map(PartResult::counted_allocations)
+                .sum::<u64>()
+                .to_string(),
+        );
+        total_row.push(format_bytes(
+            results
+                .iter()
+                .filter_map(|result| result.allocations)
+                .map(|allocations| allocations.peak_bytes)
+                .max()
+                .unwrap_or_default(),
+        ));
+    }
+    rows.push(total_row);
+
+    let mut header = vec![
+        "Year", "Day", "Input", "Part", "Answer", "Status", "Time", "Share",
+    ];
+    if with_allocations {
+        header.extend(["Allocations", "Peak"]);
+    }
+    let header: Vec<String> = header.into_iter().map(String::from).collect();
+    let mut widths: Vec<usize> = header.iter().map(|column| column.chars().count()).collect();
+    for row in &rows {
+        for (width, column) in widths.iter_mut().zip(row) {
+            *width = (*width).max(column.chars().count());
+        }
+    }
+
+    // The answer and every number are aligned to the right.
+    let format_row = |row: &[String]| {
+        let columns: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(index, (column, &width))| match index {
+                0..=3 | 5 => format!("{:<width$}", column),
+                _ => format!("{:>width$}", column),
+            })
+            .collect();
+        columns.join("  ")
+    };
+
+    let mut table = format!("{}\n", format_row(&header));
+    let separator = "-".repeat(widths.iter().sum::<usize>() + 2 * (widths.len() - 1));
+    table.push_str(&format!("{}\n", separator));
+    for (result, row) in ordered.iter().zip(&rows) {
+        let line = format_row(row);
+        if slowest.contains(&(result.event, result.input.as_str(), result.part)) {
+            if bold {
+                table.push_str(&format!("\x1b[1m{}  ◀ slow\x1b[0m\n", line));
+            } else {
+                table.push_str(&format!("{}  ◀ slow\n", line));
+            }
+        } else {
+            table.push_str(&format!("{}\n", line));
+        }
+    }
+    table.push_str(&format!("{}\n", separator));
+    table.push_str(&format!("{}\n", format_row(rows.last().unwrap())));
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(answer: Option<&str>, status: Status) -> PartResult {
+        PartResult {
+            event: Event::new("2023".parse().unwrap(), "01".parse().unwrap()),
+            input: "default".to_string(),
+            part: Part::B,
+            answer: answer.map(Answer::from),
+            expected: None,
+            status,
+            error: None,
+            parse_time: Some(Duration::from_nanos(10)),
+            shared_parse: false,
+            solve_time: Duration::from_nanos(32),
+            stats: None,
+            allocations: None,
+            parse_allocations: None,
+        }
+    }
+
+    #[test]
+    fn test_csv_quotes_multi_line_answers() {
+        let results = [
+            result(Some("#.\n.#"), Status::Solved),
+            result(None, Status::Unsolved),
+        ];
+        assert_eq!(
+            to_csv(&results),
+            "year,day,input,part,answer,expected,status,error,elapsed_ns,parse_ns,shared_parse,solve_ns,runs,iterations,min_ns,median_ns,mean_ns,std_dev_ns,allocations,allocated_bytes,peak_bytes,parse_allocations\n\
+             2023,01,default,B,\"#.\n.#\",,solved,,42,10,false,32,,,,,,,,,,\n\
+             2023,01,default,B,,,unsolved,,42,10,false,32,,,,,,,,,,\n"
+        );
+    }
+
+    #[test]
+    fn test_json_records() {
+        let json: serde_json::Value =
+            serde_json::from_str(&to_json(&[result(Some("7"), Status::Solved)])).unwrap();
+        assert_eq!(json[0]["year"], "2023");
+        assert_eq!(json[0]["day"], "01");
+        assert_eq!(json[0]["part"], "B");
+        assert_eq!(json[0]["answer"], 7);
+        assert_eq!(json[0]["status"], "solved");
+        assert_eq!(json[0]["elapsed_ns"], 42);
+    }
+
+    #[test]
+    fn test_summary_sorted_by_time() {
+        let mut fast = result(Some("1"), Status::Solved);
+        fast.part = Part::A;
+        let mut slow = result(Some("2"), Status::Solved);
+        slow.solve_time = Duration::from_nanos(116);
+        let table = summary_table(&[fast, slow], SortOrder::Time, false);
+        let rows: Vec<Vec<&str>> = table
+            .lines()
+            .map(|line| line.split_whitespace().collect())
+            .collect();
+        assert_eq!(rows[2][..6], ["2023", "01", "default", "B", "2", "solved"]);
+        assert_eq!(rows[2][7..], ["75.0%", "◀", "slow"]);
+        assert_eq!(rows[3][..6], ["2023", "01", "default", "A", "1", "solved"]);
+        assert_eq!(rows[3][7..], ["25.0%"]);
+        assert_eq!(rows[5][0], "Total");
+    }
+
+    #[test]
+    fn test_summary_counts_shared_parse_once() {
+        let mut first = result(Some("1"), Status::Solved);
+        first.part = Part::A;
+        first.parse_time = Some(Duration::from_nanos(60));
+        first.solve_time = Duration::from_nanos(20);
+        let mut second = result(Some("2"), Status::Solved);
+        second.parse_time = Some(Duration::from_nanos(60));
+        second.shared_parse = true;
+        second.solve_time = Duration::from_nanos(20);
+        let table = summary_table(&[first, second], SortOrder::Day, false);
+        let rows: Vec<Vec<&str>> = table
+            .lines()
+            .map(|line| line.split_whitespace().collect())
+            .collect();
+        assert_eq!(rows[2][6..8], ["80ns", "80.0%"]);
+        assert_eq!(rows[3][6..8], ["80ns", "20.0%"]);
+        assert_eq!(rows[5][1..], ["100ns", "100.0%"]);
+    }
+
+    #[test]
+    fn test_summary_shows_allocations_when_counted() {
+        let without = summary_table(&[result(Some("1"), Status::Solved)], SortOrder::Day, false);
+        assert!(!without.contains("Allocations"));
+
+        let mut counted = result(Some("1"), Status::Solved);
+        counted.allocations = Some(AllocStats {
+            allocations: 12,
+            bytes: 4096,
+            peak_bytes: 2048,
+            retained_bytes: 0,
+        });
+        let mut uncounted = result(Some("2"), Status::Solved);
+        uncounted.part = Part::A;
+        let table = summary_table(&[counted, uncounted], SortOrder::Day, false);
+        let rows: Vec<Vec<&str>> = table
+            .lines()
+            .map(|line| line.split_whitespace().collect())
+            .collect();
+        assert_eq!(rows[0][8..], ["Allocations", "Peak"]);
+        assert_eq!(rows[2][8..11], ["12", "2.0", "KiB"]);
+        assert_eq!(rows[3][8..10], ["-", "-"]);
+        assert_eq!(rows[5][3..], ["12", "2.0", "KiB"]);
+    }
+
+    #[test]
+    fn test_summary_counts_shared_parse_allocations_once() {
+        let with_allocations = |part, allocations, shared_parse| {
+            let mut result = result(Some("1"), Status::Solved);
+            result.part = part;
+            result.shared_parse = shared_parse;
+            result.allocations = Some(AllocStats {
+                allocations,
+                bytes: 512,
+                peak_bytes: 512,
+                retained_bytes: 0,
+            });
+            result.parse_allocations = Some(10);
+            result
+        };
+        let results = [
+            with_allocations(Part::A, 12, false),
+            with_allocations(Part::B, 15, true),
+        ];
+        let table = summary_table(&results, SortOrder::Day, false);
+        let total: Vec<&str> = table.lines().last().unwrap().split_whitespace().collect();
+        assert_eq!(total[3..], ["17", "512", "B"]);
+    }
+
+    #[test]
+    fn test_json_line_round_trip() {
+        let result = result(Some("#.\n.#"), Status::TimedOut);
+        let round_trip = from_json_line(&to_json_line(&result)).unwrap();
+        assert_eq!(round_trip.event, result.event);
+        assert_eq!(round_trip.input, result.input);
+        assert_eq!(round_trip.answer, result.answer);
+        assert_eq!(round_trip.status, result.status);
+        assert_eq!(round_trip.elapsed(), result.elapsed());
+
+        let raw_input = PartResult {
+            parse_time: None,
+            ..result
+        };
+        let line = to_json_line(&raw_input);
+        assert!(!line.contains("parse_ns"));
+        assert_eq!(from_json_line(&line).unwrap().parse_time, None);
+    }
+}
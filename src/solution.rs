@@ -0,0 +1,103 @@
+use crate::{Answer, AocError, Part};
+use std::any::{Any, TypeId};
+use std::marker::PhantomData;
+
+/// A day whose input is parsed once and then shared by both parts. A part
+/// returns `Ok(None)` while it is not solved yet.
+pub trait Solution {
+    type Parsed;
+    type AnswerA: Into<Answer>;
+    type AnswerB: Into<Answer>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, AocError>;
+    fn part_a(parsed: &Self::Parsed) -> Result<Option<Self::AnswerA>, AocError>;
+    fn part_b(parsed: &Self::Parsed) -> Result<Option<Self::AnswerB>, AocError>;
+}
+
+/// What a part returns: `Option<T>`, with `None` for a part that is not solved
+/// yet, or `Result<T, AocError>` for a part that can reject its input.
+pub trait PartOutput {
+    fn into_outcome(self) -> Result<Option<Answer>, AocError>;
+}
+
+impl<T: Into<Answer>> PartOutput for Option<T> {
+    fn into_outcome(self) -> Result<Option<Answer>, AocError> {
+        Ok(self.map(Into::into))
+    }
+}
+
+impl<T: Into<Answer>> PartOutput for Result<T, AocError> {
+    fn into_outcome(self) -> Result<Option<Answer>, AocError> {
+        self.map(|answer| Some(answer.into()))
+    }
+}
+
+/// Type-erased view of a single part, as stored in the day registry.
+pub trait PartSolver: Sync {
+    /// Identifies the parse step of the part, `None` if it solves the raw
+    /// input. Parts with the same parser can share what it parsed.
+    fn parser(&self) -> Option<TypeId>;
+    /// Only called for parts with a parser.
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, AocError>;
+    /// `parsed` is what the parser of the part made of `input`, `None` for
+    /// parts without one.
+    fn solve(&self, input: &str, parsed: Option<&dyn Any>) -> Result<Option<Answer>, AocError>;
+}
+
+/// Plain `fn(&str) -> Option<T>` parts have no parsing step of their own, all
+/// of their work counts as solving.
+impl<F, O> PartSolver for F
+where
+    F: Fn(&str) -> O + Sync,
+    O: PartOutput,
+{
+    fn parser(&self) -> Option<TypeId> {
+        None
+    }
+
+    fn parse(&self, _input: &str) -> Result<Box<dyn Any>, AocError> {
+        unreachable!("Parts without a parser solve the raw input")
+    }
+
+    fn solve(&self, input: &str, _parsed: Option<&dyn Any>) -> Result<Option<Answer>, AocError> {
+        self(input).into_outcome()
+    }
+}
+
+pub struct SolutionPart<S> {
+    part: Part,
+    solution: PhantomData<fn() -> S>,
+}
+
+impl<S> SolutionPart<S> {
+    pub const fn new(part: Part) -> Self {
+        Self {
+            part,
+            solution: PhantomData,
+        }
+    }
+}
+
+impl<S> PartSolver for SolutionPart<S>
+where
+    S: Solution + 'static,
+    S::Parsed: 'static,
+{
+    fn parser(&self) -> Option<TypeId> {
+        Some(TypeId::of::<S>())
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, AocError> {
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn solve(&self, _input: &str, parsed: Option<&dyn Any>) -> Result<Option<Answer>, AocError> {
+        let parsed = parsed
+            .and_then(|parsed| parsed.downcast_ref::<S::Parsed>())
+            .expect("Parsed input does not belong to this part");
+        Ok(match self.part {
+            Part::A => S::part_a(parsed)?.map(Into::into),
+            Part::B => S::part_b(parsed)?.map(Into::into),
+        })
+    }
+}
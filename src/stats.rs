@@ -0,0 +1,174 @@
+use crate::PartSolver;
+use std::any::Any;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Parts faster than this are run several times per sample, so that timer
+/// resolution does not dominate the measurement.
+const TARGET_SAMPLE_TIME: Duration = Duration::from_millis(1);
+const MAX_ITERATIONS: u32 = 1_000_000;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct RunConfig {
+    pub runs: usize,
+    pub warmup: usize,
+    /// Runs every part in its own worker process and gives up on it after
+    /// this long.
+    pub timeout: Option<Duration>,
+}
+
+impl Default for RunConfig {
+    fn default() -> Self {
+        Self {
+            runs: 1,
+            warmup: 0,
+            timeout: None,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct TimingStats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub std_dev: Duration,
+    pub runs: usize,
+    pub iterations: u32,
+}
+
+fn median(sorted: &[Duration]) -> Duration {
+    let mid = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) {
+        (sorted[mid - 1] + sorted[mid]) / 2
+    } else {
+        sorted[mid]
+    }
+}
+
+impl TimingStats {
+    /// `samples` must not be empty.
+    pub fn from_samples(samples: &[Duration], iterations: u32) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let nanos: Vec<f64> = sorted.iter().map(|d| d.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / nanos.len() as f64;
+        let variance = if nanos.len() > 1 {
+            nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / (nanos.len() - 1) as f64
+        } else {
+            0.0
+        };
+
+        TimingStats {
+            min: sorted[0],
+            median: median(&sorted),
+            mean: Duration::from_nanos(mean.round() as u64),
+            std_dev: Duration::from_nanos(variance.sqrt().round() as u64),
+            runs: sorted.len(),
+            iterations,
+        }
+    }
+}
+
+/// Picks how often a part has to run for one sample to take at least
+/// `TARGET_SAMPLE_TIME`, based on a single run that took `single_run`.
+fn iterations_for(single_run: Duration) -> u32 {
+    if single_run >= TARGET_SAMPLE_TIME {
+        return 1;
+    }
+    let single_run = single_run.as_nanos().max(1);
+    let iterations = TARGET_SAMPLE_TIME.as_nanos().div_ceil(single_run);
+    iterations.min(MAX_ITERATIONS as u128) as u32
+}
+
+/// Runs `f` `iterations` times per run, `config.warmup` runs untimed and then
+/// `config.runs` timed ones. Returns the time of a single iteration per run.
+fn sample(config: &RunConfig, iterations: u32, mut f: impl FnMut()) -> Vec<Duration> {
+    for _ in 0..config.warmup * iterations as usize {
+        f();
+    }
+    (0..config.runs.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            for _ in 0..iterations {
+                f();
+            }
+            start.elapsed() / iterations
+        })
+        .collect()
+}
+
+/// Samples the parse step of `solver`, which took `single_run` once.
+pub fn sample_parse(
+    solver: &dyn PartSolver,
+    input: &str,
+    config: &RunConfig,
+    single_run: Duration,
+) -> Vec<Duration> {
+    sample(config, iterations_for(single_run), || {
+        let _ = black_box(solver.parse(black_box(input)));
+    })
+}
+
+/// Samples solving `parsed`, which took `single_run` once. Returns the
+/// statistics of the total time per run, counting the `parse_samples` of the
+/// parse step if the part has one, along with the median parse and solve
+/// times. `iterations` of the statistics are those of the solve step.
+pub fn sample_solve(
+    solver: &dyn PartSolver,
+    input: &str,
+    parsed: Option<&dyn Any>,
+    parse_samples: Option<&[Duration]>,
+    config: &RunConfig,
+    single_run: Duration,
+) -> (TimingStats, Option<Duration>, Duration) {
+    let iterations = iterations_for(single_run);
+    let mut solve_samples = sample(config, iterations, || {
+        let _ = black_box(solver.solve(black_box(input), black_box(parsed)));
+    });
+
+    let totals: Vec<Duration> = match parse_samples {
+        Some(parse_samples) => parse_samples
+            .iter()
+            .zip(&solve_samples)
+            .map(|(parse_time, solve_time)| *parse_time + *solve_time)
+            .collect(),
+        None => solve_samples.clone(),
+    };
+    let parse_time = parse_samples.map(|parse_samples| {
+        let mut sorted = parse_samples.to_vec();
+        sorted.sort();
+        median(&sorted)
+    });
+    solve_samples.sort();
+
+    (
+        TimingStats::from_samples(&totals, iterations),
+        parse_time,
+        median(&solve_samples),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_from_samples() {
+        let samples = [2, 4, 4, 4, 5, 5, 7, 9].map(Duration::from_nanos);
+        let stats = TimingStats::from_samples(&samples, 1);
+        assert_eq!(stats.min, Duration::from_nanos(2));
+        assert_eq!(stats.median, Duration::from_nanos(4));
+        assert_eq!(stats.mean, Duration::from_nanos(5));
+        assert_eq!(stats.std_dev, Duration::from_nanos(2));
+        assert_eq!(stats.runs, 8);
+    }
+
+    #[test]
+    fn test_iterations_for_fast_parts() {
+        assert_eq!(iterations_for(Duration::from_millis(3)), 1);
+        assert_eq!(iterations_for(Duration::from_micros(10)), 100);
+        assert_eq!(iterations_for(Duration::ZERO), MAX_ITERATIONS);
+    }
+}
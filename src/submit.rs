@@ -0,0 +1,324 @@
+use crate::answers::record_answers;
+use crate::client::Client;
+use crate::inputs::DEFAULT_INPUT;
+use crate::{data_path, Answer, Event, Part};
+use regex::Regex;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::fs::{create_dir_all, read_to_string, write};
+use std::io::{self, ErrorKind};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// How the puzzle server judged a submitted answer.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction.
+    Wrong,
+    /// Another answer was submitted too recently. Nothing was judged.
+    RateLimited {
+        wait: Option<Duration>,
+    },
+    /// The part is either already solved or still locked.
+    WrongLevel,
+}
+
+impl Outcome {
+    /// Reads the outcome from the page the server answers a submission with.
+    pub fn parse(response: &str) -> Option<Self> {
+        if response.contains("That's the right answer") {
+            Some(Outcome::Correct)
+        } else if response.contains("your answer is too high") {
+            Some(Outcome::TooHigh)
+        } else if response.contains("your answer is too low") {
+            Some(Outcome::TooLow)
+        } else if response.contains("That's not the right answer") {
+            Some(Outcome::Wrong)
+        } else if response.contains("You gave an answer too recently") {
+            let wait = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait")
+                .unwrap()
+                .captures(response)
+                .map(|captures| {
+                    let minutes: u64 = captures.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+                    let seconds: u64 = captures[2].parse().unwrap();
+                    Duration::from_secs(minutes * 60 + seconds)
+                });
+            Some(Outcome::RateLimited { wait })
+        } else if response.contains("You don't seem to be solving the right level") {
+            Some(Outcome::WrongLevel)
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "Correct"),
+            Outcome::TooHigh => write!(f, "Too high"),
+            Outcome::TooLow => write!(f, "Too low"),
+            Outcome::Wrong => write!(f, "Wrong"),
+            Outcome::RateLimited { wait: Some(wait) } => write!(
+                f,
+                "Rate limited, try again in {}",
+                humantime::format_duration(*wait)
+            ),
+            Outcome::RateLimited { wait: None } => write!(f, "Rate limited, try again later"),
+            Outcome::WrongLevel => write!(f, "Part is already solved or still locked"),
+        }
+    }
+}
+
+/// What earlier guesses for a part revealed: answers up to `low` are too low,
+/// answers from `high` on are too high.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub struct Bounds {
+    pub low: Option<i128>,
+    pub high: Option<i128>,
+}
+
+/// Why an answer is not worth submitting.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Refusal {
+    TooHigh { high: i128 },
+    TooLow { low: i128 },
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::TooHigh { high } => write!(f, "{} was already too high", high),
+            Refusal::TooLow { low } => write!(f, "{} was already too low", low),
+        }
+    }
+}
+
+fn numeric(answer: &Answer) -> Option<i128> {
+    match answer {
+        Answer::Unsigned(value) => Some(*value as i128),
+        Answer::Signed(value) => Some(*value as i128),
+        Answer::Text(_) | Answer::Grid(_) => None,
+    }
+}
+
+impl Bounds {
+    /// Rules out numbers outside the bounds. Other answers are never refused.
+    pub fn check(&self, answer: &Answer) -> Result<(), Refusal> {
+        let Some(value) = numeric(answer) else {
+            return Ok(());
+        };
+        match (self.low, self.high) {
+            (Some(low), _) if value <= low => Err(Refusal::TooLow { low }),
+            (_, Some(high)) if value >= high => Err(Refusal::TooHigh { high }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Narrows the bounds with the outcome of submitting `answer`.
+    pub fn update(&mut self, answer: &Answer, outcome: &Outcome) {
+        let Some(value) = numeric(answer) else {
+            return;
+        };
+        match outcome {
+            Outcome::TooLow => self.low = Some(self.low.map_or(value, |low| low.max(value))),
+            Outcome::TooHigh => self.high = Some(self.high.map_or(value, |high| high.min(value))),
+            _ => {}
+        }
+    }
+}
+
+/// Bounds are kept next to the recorded answers in
+/// `data/<year>/answers/day_XX.bounds`, as `<part> low: <n>` and
+/// `<part> high: <n>` lines.
+fn bounds_path(event: Event) -> PathBuf {
+    data_path(event.year, "answers").join(format!("day_{}.bounds", event.day))
+}
+
+fn parse_bounds(content: &str) -> BTreeMap<Part, Bounds> {
+    let mut bounds = BTreeMap::new();
+    for line in content.lines() {
+        let Some((key, value)) = line.split_once(": ") else {
+            continue;
+        };
+        let (Some((part, side)), Ok(value)) = (key.trim().split_once(' '), value.trim().parse())
+        else {
+            continue;
+        };
+        let Ok(part) = part.parse() else {
+            continue;
+        };
+        let part_bounds: &mut Bounds = bounds.entry(part).or_default();
+        match side {
+            "low" => part_bounds.low = Some(value),
+            "high" => part_bounds.high = Some(value),
+            _ => {}
+        }
+    }
+    bounds
+}
+
+fn format_bounds(bounds: &BTreeMap<Part, Bounds>) -> String {
+    let mut content = String::new();
+    for (part, part_bounds) in bounds {
+        if let Some(low) = part_bounds.low {
+            content.push_str(&format!("{} low: {}\n", part, low));
+        }
+        if let Some(high) = part_bounds.high {
+            content.push_str(&format!("{} high: {}\n", part, high));
+        }
+    }
+    content
+}
+
+pub fn read_bounds(event: Event) -> io::Result<BTreeMap<Part, Bounds>> {
+    match read_to_string(bounds_path(event)) {
+        Ok(content) => Ok(parse_bounds(&content)),
+        Err(why) if why.kind() == ErrorKind::NotFound => Ok(BTreeMap::new()),
+        Err(why) => Err(why),
+    }
+}
+
+pub fn record_bounds(event: Event, bounds: &BTreeMap<Part, Bounds>) -> io::Result<()> {
+    let path = bounds_path(event);
+    if let Some(answers_dir) = path.parent() {
+        create_dir_all(answers_dir)?;
+    }
+    write(path, format_bounds(bounds))
+}
+
+/// Posts `answer` and reads how it was judged.
+pub fn post_answer(
+    client: &Client,
+    event: Event,
+    part: Part,
+    answer: &Answer,
+) -> io::Result<Outcome> {
+    let path = format!("/{}/day/{}/answer", event.year, usize::from(event.day) + 1);
+    let level = (usize::from(part) + 1).to_string();
+    let response = client.post_form(&path, &[("level", level), ("answer", answer.to_string())])?;
+    Outcome::parse(&response).ok_or_else(|| {
+        io::Error::new(
+            ErrorKind::InvalidData,
+            "unexpected response from the server",
+        )
+    })
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Submission {
+    /// Not sent, as earlier guesses already rule the answer out.
+    Refused(Refusal),
+    Sent(Outcome),
+}
+
+/// Submits the answer to a part unless the bounds from earlier guesses rule it
+/// out. Afterwards, the bounds are narrowed and a correct answer is recorded.
+pub fn submit_answer(
+    client: &Client,
+    event: Event,
+    part: Part,
+    answer: &Answer,
+) -> io::Result<Submission> {
+    let mut bounds = read_bounds(event)?;
+    let part_bounds = bounds.entry(part).or_default();
+    if let Err(refusal) = part_bounds.check(answer) {
+        return Ok(Submission::Refused(refusal));
+    }
+
+    let outcome = post_answer(client, event, part, answer)?;
+    match outcome {
+        Outcome::TooHigh | Outcome::TooLow => {
+            part_bounds.update(answer, &outcome);
+            record_bounds(event, &bounds)?;
+        }
+        Outcome::Correct => {
+            record_answers(
+                event,
+                DEFAULT_INPUT,
+                &BTreeMap::from([(part, answer.clone())]),
+            )?;
+        }
+        _ => {}
+    }
+    Ok(Submission::Sent(outcome))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_outcome_is_read_from_the_response() {
+        let responses = [
+            (
+                "That's the right answer! You are one gold star closer.",
+                Outcome::Correct,
+            ),
+            (
+                "That's not the right answer; your answer is too high.",
+                Outcome::TooHigh,
+            ),
+            (
+                "That's not the right answer; your answer is too low.",
+                Outcome::TooLow,
+            ),
+            (
+                "That's not the right answer. If you're stuck, ...",
+                Outcome::Wrong,
+            ),
+            (
+                "You gave an answer too recently. You have 1m 5s left to wait.",
+                Outcome::RateLimited {
+                    wait: Some(Duration::from_secs(65)),
+                },
+            ),
+            (
+                "You don't seem to be solving the right level. Did you already complete it?",
+                Outcome::WrongLevel,
+            ),
+        ];
+        for (message, expected) in responses {
+            assert_eq!(Outcome::parse(message), Some(expected));
+        }
+        assert_eq!(Outcome::parse("Something else entirely"), None);
+    }
+
+    #[test]
+    fn test_bounds_refuse_ruled_out_guesses() {
+        let mut bounds = Bounds::default();
+        bounds.update(&Answer::from(100u32), &Outcome::TooLow);
+        bounds.update(&Answer::from(500u32), &Outcome::TooHigh);
+        bounds.update(&Answer::from(50u32), &Outcome::TooLow);
+        bounds.update(&Answer::from(300u32), &Outcome::Wrong);
+        assert_eq!(
+            bounds,
+            Bounds {
+                low: Some(100),
+                high: Some(500)
+            }
+        );
+
+        assert_eq!(
+            bounds.check(&Answer::from(100u32)),
+            Err(Refusal::TooLow { low: 100 })
+        );
+        assert_eq!(
+            bounds.check(&Answer::from(-3i64)),
+            Err(Refusal::TooLow { low: 100 })
+        );
+        assert_eq!(
+            bounds.check(&Answer::from(501u32)),
+            Err(Refusal::TooHigh { high: 500 })
+        );
+        assert_eq!(bounds.check(&Answer::from(499u32)), Ok(()));
+        assert_eq!(bounds.check(&Answer::from("EFGH")), Ok(()));
+
+        let recorded = BTreeMap::from([(Part::B, bounds)]);
+        assert_eq!(format_bounds(&recorded), "B low: 100\nB high: 500\n");
+        assert_eq!(parse_bounds(&format_bounds(&recorded)), recorded);
+    }
+}
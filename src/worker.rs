@@ -0,0 +1,217 @@
+use crate::report::{from_json_line, to_json_line};
+use crate::{get_solver, run_parts, Event, Input, Part, PartResult, PartSolver, RunConfig, Status};
+use std::io::{stdin, stdout, BufRead, BufReader, Read, Write};
+use std::process::{exit, Command, Stdio};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// The hidden CLI subcommand a worker process is started with.
+pub const WORKER_COMMAND: &str = "worker";
+
+/// Entry point of a worker process: runs parts of a day on the input passed
+/// through stdin and prints the result of each part on its own line of stdout
+/// as soon as it is done. The worker does not know the input's name or
+/// answers, `run_in_worker` fills them in.
+pub fn worker_main(event: Event, parts: &[Part], config: &RunConfig) {
+    let mut input = Input::default();
+    if let Err(why) = stdin().read_to_string(&mut input.text) {
+        eprintln!("Failed to get input for {}: {}", event, why);
+        exit(1);
+    }
+    let mut solvers: Vec<(Part, &dyn PartSolver)> = vec![];
+    for &part in parts {
+        match get_solver(event, part) {
+            Ok(solver) => solvers.push((part, solver)),
+            Err(why) => {
+                eprintln!("{}", why);
+                exit(1);
+            }
+        }
+    }
+
+    run_parts(event, &solvers, &input, config, |result| {
+        // A solver may have left a line unfinished.
+        println!("\n{}", to_json_line(&result));
+        stdout().flush().ok();
+    });
+}
+
+fn failed_result(
+    event: Event,
+    input: &Input,
+    part: Part,
+    status: Status,
+    error: String,
+    elapsed: Duration,
+) -> PartResult {
+    PartResult {
+        event,
+        input: input.name.clone(),
+        part,
+        answer: None,
+        expected: input.answers.get(&part).cloned(),
+        status,
+        error: Some(error),
+        parse_time: None,
+        shared_parse: false,
+        solve_time: elapsed,
+        stats: None,
+        allocations: None,
+        parse_allocations: None,
+    }
+}
+
+/// Runs parts of a day on `input` in a separate process of the current
+/// executable, which parses the input once for all of them. A part that takes
+/// longer than `timeout` gets the worker killed, and the parts after it are
+/// run in a new one.
+pub fn run_in_worker(
+    event: Event,
+    parts: &[Part],
+    input: &Input,
+    config: &RunConfig,
+    timeout: Duration,
+) -> Vec<PartResult> {
+    let mut results = Vec::with_capacity(parts.len());
+    while results.len() < parts.len() {
+        run_worker(
+            event,
+            &parts[results.len()..],
+            input,
+            config,
+            timeout,
+            &mut results,
+        );
+    }
+    results
+}
+
+/// Runs `parts` in a single worker until all of them are done or one of them
+/// failed without a result, which is then reported in its place.
+fn run_worker(
+    event: Event,
+    parts: &[Part],
+    input: &Input,
+    config: &RunConfig,
+    timeout: Duration,
+    results: &mut Vec<PartResult>,
+) {
+    let exe = match std::env::current_exe() {
+        Ok(exe) => exe,
+        Err(why) => {
+            let why = format!("Failed to start worker: {}", why);
+            results.push(failed_result(
+                event,
+                input,
+                parts[0],
+                Status::Panicked,
+                why,
+                Duration::ZERO,
+            ));
+            return;
+        }
+    };
+
+    let spawned = Command::new(exe)
+        .arg(WORKER_COMMAND)
+        .arg("--year")
+        .arg(event.year.to_string())
+        .arg(event.day.to_string())
+        .args(parts.iter().map(Part::to_string))
+        .arg("--runs")
+        .arg(config.runs.to_string())
+        .arg("--warmup")
+        .arg(config.warmup.to_string())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn();
+    let mut child = match spawned {
+        Ok(child) => child,
+        Err(why) => {
+            let why = format!("Failed to start worker: {}", why);
+            results.push(failed_result(
+                event,
+                input,
+                parts[0],
+                Status::Panicked,
+                why,
+                Duration::ZERO,
+            ));
+            return;
+        }
+    };
+
+    // Feed the input from its own thread, a worker that hangs before reading
+    // all of it must not keep us from killing it.
+    let mut stdin = child.stdin.take().unwrap();
+    let text = input.text.clone();
+    thread::spawn(move || stdin.write_all(text.as_bytes()));
+
+    // Drain stdout while waiting, a chatty solver could otherwise fill the
+    // pipe and block until it gets killed.
+    let stdout = child.stdout.take().unwrap();
+    let (sender, receiver) = mpsc::channel();
+    let reader = thread::spawn(move || {
+        for line in BufReader::new(stdout).lines() {
+            let Ok(line) = line else {
+                break;
+            };
+            if let Some(result) = from_json_line(&line) {
+                if sender.send(result).is_err() {
+                    break;
+                }
+            }
+        }
+    });
+
+    let mut start = Instant::now();
+    for &part in parts {
+        let remaining = timeout.saturating_sub(start.elapsed());
+        match receiver.recv_timeout(remaining) {
+            Ok(result) => {
+                results.push(PartResult {
+                    input: input.name.clone(),
+                    expected: input.answers.get(&result.part).cloned(),
+                    ..result
+                });
+                start = Instant::now();
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                let elapsed = start.elapsed();
+                child.kill().ok();
+                child.wait().ok();
+                reader.join().ok();
+                let why = format!("Timed out after {}", humantime::format_duration(timeout));
+                results.push(failed_result(
+                    event,
+                    input,
+                    part,
+                    Status::TimedOut,
+                    why,
+                    elapsed,
+                ));
+                return;
+            }
+            Err(RecvTimeoutError::Disconnected) => {
+                let elapsed = start.elapsed();
+                let why = match child.wait() {
+                    Ok(exit_status) => format!("Worker exited without a result ({})", exit_status),
+                    Err(why) => format!("Worker exited without a result: {}", why),
+                };
+                reader.join().ok();
+                results.push(failed_result(
+                    event,
+                    input,
+                    part,
+                    Status::Panicked,
+                    why,
+                    elapsed,
+                ));
+                return;
+            }
+        }
+    }
+    child.wait().ok();
+    reader.join().ok();
+}
@@ -1,3 +1,5 @@
+// Some of the solutions were written before lints that are allowed on their
+// modules below, and are kept as they were.
 pub mod day_01;
 pub mod day_02;
 pub mod day_03;
@@ -8,6 +10,7 @@ pub mod day_07;
 pub mod day_08;
 pub mod day_09;
 
+#[allow(clippy::needless_range_loop, clippy::ptr_arg)]
 pub mod day_10;
 
 pub mod day_11;
@@ -16,6 +19,7 @@ pub mod day_12;
 
 pub mod day_13;
 
+#[allow(clippy::ptr_arg)]
 pub mod day_14;
 
 pub mod day_15;
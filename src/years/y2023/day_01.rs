@@ -0,0 +1,84 @@
+use crate::{AocError, Solution};
+use aoc_macros::aoc;
+use std::collections::HashMap;
+
+use std::str::FromStr;
+
+pub struct Day01;
+
+fn calibration_value(line: &str) -> Result<u32, AocError> {
+    let (Some(first), Some(last)) = (line.find(char::is_numeric), line.rfind(char::is_numeric))
+    else {
+        return Err(AocError::invalid(format!("{} has no digit", line)));
+    };
+    Ok(u32::from_str(&format!(
+        "{}{}",
+        line.chars().nth(first).unwrap(),
+        line.chars().nth(last).unwrap()
+    ))
+    .unwrap())
+}
+
+#[aoc(year = 2023, day = 1)]
+impl Solution for Day01 {
+    type Parsed = Vec<String>;
+    type AnswerA = u32;
+    type AnswerB = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed, AocError> {
+        Ok(input.lines().map(str::to_string).collect())
+    }
+
+    fn part_a(lines: &Self::Parsed) -> Result<Option<u32>, AocError> {
+        lines
+            .iter()
+            .map(|l| calibration_value(l))
+            .sum::<Result<_, _>>()
+            .map(Some)
+    }
+
+    fn part_b(lines: &Self::Parsed) -> Result<Option<u32>, AocError> {
+        let digits = HashMap::from([
+            ("one", "1"),
+            ("two", "2"),
+            ("three", "3"),
+            ("four", "4"),
+            ("five", "5"),
+            ("six", "6"),
+            ("seven", "7"),
+            ("eight", "8"),
+            ("nine", "9"),
+        ]);
+
+        let two_digit_combos = HashMap::from([
+            ("oneight", "18"),
+            ("twone", "21"),
+            ("threight", "38"),
+            ("fiveight", "58"),
+            ("sevenine", "79"),
+            ("eightwo", "82"),
+            ("eighthree", "83"),
+            ("nineight", "98"),
+        ]);
+
+        lines
+            .iter()
+            .map(|l| {
+                let mut l = l.to_string();
+                for d in &two_digit_combos {
+                    l = l.replace(d.0, d.1);
+                }
+                l
+            })
+            .map(|l| {
+                let mut l = l.to_string();
+                for d in &digits {
+                    l = l.replace(d.0, d.1);
+                }
+                l
+            })
+            .map(|l| calibration_value(&l))
+            .sum::<Result<_, _>>()
+            .map(Some)
+    }
+}
@@ -0,0 +1,83 @@
+use crate::{AocError, Solution};
+use aoc_macros::aoc;
+use regex::Regex;
+
+pub struct Day02;
+
+/// The red, green and blue cubes shown in each reveal of a game.
+pub struct Game {
+    id: u32,
+    reveals: Vec<[u32; 3]>,
+}
+
+#[aoc(year = 2023, day = 2)]
+impl Solution for Day02 {
+    type Parsed = Vec<Game>;
+    type AnswerA = u32;
+    type AnswerB = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed, AocError> {
+        let cube_regexes =
+            [r"(\d+) red", r"(\d+) green", r"(\d+) blue"].map(|cubes| Regex::new(cubes).unwrap());
+
+        input
+            .lines()
+            .map(|line| {
+                let (game, reveals) = line
+                    .split_once(": ")
+                    .ok_or_else(|| AocError::parse_at(input, line, "Expected a game"))?;
+                let id = game
+                    .trim_start_matches("Game ")
+                    .parse()
+                    .map_err(|_| AocError::parse_at(input, game, "Failed to parse game id"))?;
+                let reveals = reveals
+                    .split("; ")
+                    .map(|reveal| {
+                        let mut rgb_cubes = [0; 3];
+                        for (cubes, regex) in rgb_cubes.iter_mut().zip(&cube_regexes) {
+                            if let Some(count) = regex.captures(reveal).and_then(|c| c.get(1)) {
+                                *cubes = count.as_str().parse().map_err(|_| {
+                                    AocError::parse_at(
+                                        input,
+                                        count.as_str(),
+                                        "Failed to parse cube count",
+                                    )
+                                })?;
+                            }
+                        }
+                        Ok(rgb_cubes)
+                    })
+                    .collect::<Result<_, AocError>>()?;
+                Ok(Game { id, reveals })
+            })
+            .collect()
+    }
+
+    fn part_a(games: &Self::Parsed) -> Result<Option<u32>, AocError> {
+        let mut id_sum = 0;
+        for game in games {
+            let valid_game = game
+                .reveals
+                .iter()
+                .all(|&[red, green, blue]| red <= 12 && green <= 13 && blue <= 14);
+            if valid_game {
+                id_sum += game.id;
+            }
+        }
+        Ok(Some(id_sum))
+    }
+
+    fn part_b(games: &Self::Parsed) -> Result<Option<u32>, AocError> {
+        let mut cube_power = 0;
+        for game in games {
+            let mut rgb_min_cubes = [0; 3];
+            for reveal in &game.reveals {
+                for (min_cubes, cubes) in rgb_min_cubes.iter_mut().zip(reveal) {
+                    *min_cubes = (*min_cubes).max(*cubes);
+                }
+            }
+            cube_power += rgb_min_cubes.iter().product::<u32>();
+        }
+        Ok(Some(cube_power))
+    }
+}
@@ -0,0 +1,132 @@
+use crate::{AocError, Solution};
+use aoc_macros::aoc;
+
+pub struct Day03;
+
+fn read_number(schematic: &[Vec<char>], col_index: usize, row_index: usize) -> (u32, usize) {
+    let mut begin_row_index = row_index;
+    let mut digit_vec = vec![];
+
+    if !schematic
+        .get(col_index)
+        .unwrap()
+        .get(row_index)
+        .unwrap()
+        .is_ascii_digit()
+    {
+        return (0, row_index);
+    }
+
+    while schematic
+        .get(col_index)
+        .unwrap()
+        .get(begin_row_index)
+        .unwrap()
+        .is_ascii_digit()
+    {
+        begin_row_index -= 1;
+    }
+    begin_row_index += 1;
+
+    let mut end_row_index = begin_row_index;
+    while let Some(digit) = schematic
+        .get(col_index)
+        .unwrap()
+        .get(end_row_index)
+        .unwrap()
+        .to_digit(10)
+    {
+        digit_vec.push(digit);
+        end_row_index += 1;
+    }
+    end_row_index -= 1;
+
+    (
+        digit_vec.iter().fold(0, |acc, digit| acc * 10 + digit),
+        end_row_index,
+    )
+}
+
+#[aoc(year = 2023, day = 3)]
+impl Solution for Day03 {
+    /// The schematic, with a border of `.` around it.
+    type Parsed = Vec<Vec<char>>;
+    type AnswerA = u32;
+    type AnswerB = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed, AocError> {
+        let mut schematic: Vec<Vec<char>> = input.lines().map(|l| l.chars().collect()).collect();
+        let Some(first_row) = schematic.first() else {
+            return Err(AocError::parse(1, 1, "Expected a schematic"));
+        };
+        let old_row_length = first_row.len();
+        let bounding_row = vec!['.'; old_row_length];
+        schematic.insert(0, bounding_row.clone());
+        schematic.push(bounding_row);
+        schematic.iter_mut().for_each(|r| {
+            r.insert(0, '.');
+            r.push('.');
+        });
+        Ok(schematic)
+    }
+
+    fn part_a(schematic: &Self::Parsed) -> Result<Option<u32>, AocError> {
+        let mut engine_number_sum = 0;
+        for (col_index, col) in schematic.iter().enumerate() {
+            for (row_index, item) in col.iter().enumerate() {
+                if item != &'.' && !item.is_ascii_digit() {
+                    for col_diff in [-1, 0, 1] {
+                        let mut begin_row_index = row_index - 1;
+                        while [-1, 0, 1].contains(&(begin_row_index as isize - row_index as isize))
+                        {
+                            let (number, last_index) = read_number(
+                                schematic,
+                                (col_index as isize + col_diff) as usize,
+                                begin_row_index,
+                            );
+                            if number == 0 {
+                                begin_row_index = last_index + 1;
+                            } else {
+                                begin_row_index = last_index + 2;
+                                engine_number_sum += number;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        Ok(Some(engine_number_sum))
+    }
+
+    fn part_b(schematic: &Self::Parsed) -> Result<Option<u32>, AocError> {
+        let mut gear_ratio_sum = 0;
+        for (col_index, col) in schematic.iter().enumerate() {
+            for (row_index, item) in col.iter().enumerate() {
+                if item == &'*' {
+                    let mut numbers = vec![];
+                    for col_diff in [-1, 0, 1] {
+                        let mut begin_row_index = row_index - 1;
+                        while [-1, 0, 1].contains(&(begin_row_index as isize - row_index as isize))
+                        {
+                            let (number, last_index) = read_number(
+                                schematic,
+                                (col_index as isize + col_diff) as usize,
+                                begin_row_index,
+                            );
+                            if number == 0 {
+                                begin_row_index = last_index + 1;
+                            } else {
+                                begin_row_index = last_index + 2;
+                                numbers.push(number);
+                            }
+                        }
+                    }
+                    if numbers.len() == 2 {
+                        gear_ratio_sum += numbers[0] * numbers[1];
+                    }
+                }
+            }
+        }
+        Ok(Some(gear_ratio_sum))
+    }
+}
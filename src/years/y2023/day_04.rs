@@ -0,0 +1,56 @@
+use crate::{AocError, Solution};
+use aoc_macros::aoc;
+use std::collections::{HashMap, HashSet};
+
+pub struct Day04;
+
+#[aoc(year = 2023, day = 4)]
+impl Solution for Day04 {
+    /// How many winning numbers each card has.
+    type Parsed = Vec<usize>;
+    type AnswerA = u32;
+    type AnswerB = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed, AocError> {
+        input
+            .lines()
+            .map(|line| {
+                let (_, numbers) = line
+                    .split_once(": ")
+                    .ok_or_else(|| AocError::parse_at(input, line, "Expected a card"))?;
+                let (winning_numbers, card_numbers) = numbers
+                    .split_once(" | ")
+                    .ok_or_else(|| AocError::parse_at(input, numbers, "Expected a `|`"))?;
+                let winning_numbers: HashSet<&str> = winning_numbers.split(' ').collect();
+                let card_numbers: HashSet<&str> = card_numbers.split_ascii_whitespace().collect();
+                Ok(winning_numbers.intersection(&card_numbers).count())
+            })
+            .collect()
+    }
+
+    fn part_a(win_counts: &Self::Parsed) -> Result<Option<u32>, AocError> {
+        let mut points = 0;
+        for &win_count in win_counts {
+            if win_count > 0 {
+                points += 2_u32.pow(win_count as u32 - 1);
+            }
+        }
+        Ok(Some(points))
+    }
+
+    fn part_b(win_counts: &Self::Parsed) -> Result<Option<u32>, AocError> {
+        let mut cards = HashMap::new();
+        for (card_id, &win_count) in win_counts.iter().enumerate() {
+            let card_id = card_id + 1;
+            cards.entry(card_id).and_modify(|v| *v += 1).or_insert(1);
+            for i in (card_id + 1)..(card_id + win_count + 1) {
+                let current_card_count = *cards.get(&card_id).unwrap();
+                cards
+                    .entry(i)
+                    .and_modify(|v| *v += current_card_count)
+                    .or_insert(current_card_count);
+            }
+        }
+        Ok(Some(cards.values().sum()))
+    }
+}
@@ -0,0 +1,188 @@
+use crate::{AocError, Solution};
+use aoc_macros::aoc;
+use std::ops::Range;
+
+pub struct Day05;
+
+type AlmanacMap = Vec<(Range<u64>, Range<u64>)>;
+
+pub struct Almanac {
+    seeds: Vec<u64>,
+    /// The source and destination ranges of each map.
+    maps: Vec<AlmanacMap>,
+}
+
+fn parse_number(input: &str, num: &str) -> Result<u64, AocError> {
+    num.parse()
+        .map_err(|_| AocError::parse_at(input, num, format!("{} is not a number", num)))
+}
+
+#[aoc(year = 2023, day = 5)]
+impl Solution for Day05 {
+    type Parsed = Almanac;
+    type AnswerA = u64;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, AocError> {
+        let mut split = input.split("\n\n");
+        let seeds = split
+            .next()
+            .and_then(|seeds| seeds.strip_prefix("seeds: "))
+            .ok_or_else(|| AocError::parse(1, 1, "Expected the seeds"))?
+            .split_whitespace()
+            .map(|seed| parse_number(input, seed))
+            .collect::<Result<_, _>>()?;
+        let maps = split
+            .map(|map| {
+                map.lines()
+                    .skip(1)
+                    .map(|range| {
+                        let nums = range
+                            .split_whitespace()
+                            .map(|num| parse_number(input, num))
+                            .collect::<Result<Vec<u64>, _>>()?;
+                        let [destination, source, length] = nums[..] else {
+                            return Err(AocError::parse_at(input, range, "Expected three numbers"));
+                        };
+                        Ok((
+                            source..(source + length),
+                            destination..(destination + length),
+                        ))
+                    })
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Almanac { seeds, maps })
+    }
+
+    fn part_a(almanac: &Self::Parsed) -> Result<Option<u64>, AocError> {
+        let mut seeds = almanac.seeds.clone();
+        for map in &almanac.maps {
+            for seed in seeds.iter_mut() {
+                *seed = map
+                    .iter()
+                    .find_map(|m| {
+                        if m.0.start <= *seed && m.0.end > *seed {
+                            Some(m.1.start + *seed - m.0.start)
+                        } else {
+                            None
+                        }
+                    })
+                    .unwrap_or(*seed);
+            }
+        }
+
+        Ok(seeds.into_iter().min())
+    }
+
+    // Same as the bruteforce below, but moves whole seed ranges through the
+    // maps, splitting them wherever a map only covers part of a range
+    fn part_b(almanac: &Self::Parsed) -> Result<Option<u64>, AocError> {
+        let mut ranges: Vec<Range<u64>> = almanac
+            .seeds
+            .chunks(2)
+            .map(|pair| pair[0]..(pair[0] + pair.get(1).copied().unwrap_or(0)))
+            .collect();
+
+        for map in almanac.maps.iter() {
+            let mut mapped = Vec::with_capacity(ranges.len());
+            while let Some(range) = ranges.pop() {
+                let overlap = map
+                    .iter()
+                    .find(|(source, _)| source.start < range.end && range.start < source.end);
+                match overlap {
+                    None => mapped.push(range),
+                    Some((source, destination)) => {
+                        let start = range.start.max(source.start);
+                        let end = range.end.min(source.end);
+                        mapped.push(
+                            (destination.start + start - source.start)
+                                ..(destination.start + end - source.start),
+                        );
+                        if range.start < start {
+                            ranges.push(range.start..start);
+                        }
+                        if end < range.end {
+                            ranges.push(end..range.end);
+                        }
+                    }
+                }
+            }
+            ranges = mapped;
+        }
+
+        Ok(ranges.into_iter().map(|range| range.start).min())
+    }
+}
+
+pub struct Day05Bruteforce;
+
+// This part of today's puzzle is proudly presented to you by your CPU
+#[aoc(year = 2023, day = 5, part = B, variant = "bruteforce")]
+impl Solution for Day05Bruteforce {
+    type Parsed = Almanac;
+    type AnswerA = u64;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, AocError> {
+        Day05::parse(input)
+    }
+
+    fn part_a(almanac: &Self::Parsed) -> Result<Option<u64>, AocError> {
+        Day05::part_a(almanac)
+    }
+
+    fn part_b(almanac: &Self::Parsed) -> Result<Option<u64>, AocError> {
+        let mut lowest_loc = u64::MAX;
+        for pair in almanac.seeds.chunks(2) {
+            let mut seeds: Vec<u64> =
+                (pair[0]..(pair[0] + pair.get(1).copied().unwrap_or(0))).collect();
+
+            for map in almanac.maps.iter() {
+                for seed in seeds.iter_mut() {
+                    *seed = map
+                        .iter()
+                        .find_map(|m| {
+                            if m.0.start <= *seed && m.0.end > *seed {
+                                Some(m.1.start + *seed - m.0.start)
+                            } else {
+                                None
+                            }
+                        })
+                        .unwrap_or(*seed);
+                }
+            }
+            if let Some(&lowest_seed_loc) = seeds.iter().min() {
+                lowest_loc = lowest_loc.min(lowest_seed_loc)
+            }
+        }
+
+        Ok(Some(lowest_loc))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::examples;
+    use crate::{Answer, Event, Part};
+    use std::fs::read_to_string;
+
+    #[test]
+    fn test_day_05_part_b_variants_agree_on_example() {
+        let event = Event::new("2023".parse().unwrap(), "05".parse().unwrap());
+        let example = examples(event)
+            .unwrap()
+            .into_iter()
+            .find(|example| example.parts == [Part::B])
+            .unwrap();
+        let input = read_to_string(&example.path).expect("Needs the part B example of 2023 day 05");
+        let part_b = Day05::part_b(&Day05::parse(&input).unwrap()).unwrap();
+        let bruteforce = Day05Bruteforce::part_b(&Day05Bruteforce::parse(&input).unwrap()).unwrap();
+        assert_eq!(
+            part_b.map(Answer::from).as_ref(),
+            example.expected.get(&Part::B)
+        );
+        assert_eq!(bruteforce, part_b);
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{AocError, Solution};
+use aoc_macros::aoc;
+
+pub struct Day06;
+
+pub struct Races {
+    times: Vec<u64>,
+    distances: Vec<u64>,
+}
+
+fn parse_numbers(input: &str, line: Option<&str>, name: &str) -> Result<Vec<u64>, AocError> {
+    let (_, numbers) = line
+        .and_then(|line| line.split_once(':'))
+        .ok_or_else(|| AocError::invalid(format!("Expected a line of {}", name)))?;
+    numbers
+        .split_whitespace()
+        .map(|t| {
+            t.parse()
+                .map_err(|_| AocError::parse_at(input, t, format!("{} is not a number", t)))
+        })
+        .collect()
+}
+
+/// The number made of the digits of all `numbers`, ignoring the spaces
+/// between them.
+fn join_digits(numbers: &[u64]) -> Result<u64, AocError> {
+    let digits: String = numbers.iter().map(u64::to_string).collect();
+    digits
+        .parse()
+        .map_err(|_| AocError::invalid(format!("{} is too large", digits)))
+}
+
+#[aoc(year = 2023, day = 6)]
+impl Solution for Day06 {
+    type Parsed = Races;
+    type AnswerA = u32;
+    type AnswerB = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed, AocError> {
+        let mut lines = input.lines();
+        Ok(Races {
+            times: parse_numbers(input, lines.next(), "times")?,
+            distances: parse_numbers(input, lines.next(), "distances")?,
+        })
+    }
+
+    fn part_a(races: &Self::Parsed) -> Result<Option<u32>, AocError> {
+        let mut margin = 1;
+        for (&time, &distance) in races.times.iter().zip(&races.distances) {
+            let mut race_sum = 0;
+            for my_time in 0..=time {
+                if (time - my_time) * my_time > distance {
+                    race_sum += 1;
+                }
+            }
+            margin *= race_sum;
+        }
+
+        Ok(Some(margin))
+    }
+
+    fn part_b(races: &Self::Parsed) -> Result<Option<u32>, AocError> {
+        let time = join_digits(&races.times)?;
+        let distance = join_digits(&races.distances)?;
+
+        let mut race_sum = 0;
+        for my_time in 0..=time {
+            if (time - my_time) * my_time > distance {
+                race_sum += 1;
+            }
+        }
+        Ok(Some(race_sum))
+    }
+}
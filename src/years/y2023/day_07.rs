@@ -1,9 +1,10 @@
+use crate::{AocError, Solution};
+use aoc_macros::aoc;
 use std::cmp::Ordering;
-use std::error::Error;
-use std::fmt::{Debug, Display, Formatter};
+use std::fmt::Debug;
 
 #[derive(Ord, PartialOrd, Eq, PartialEq, Copy, Clone, Debug)]
-enum Card {
+pub enum Card {
     Two,
     Three,
     Four,
@@ -36,27 +37,12 @@ enum Card2 {
     Ace,
 }
 
-#[derive(Debug, Clone)]
-struct ParseCardError {
-    failed_string: String,
+fn card_error(c: char) -> AocError {
+    AocError::invalid(format!("Failed to parse Card: {}", c))
 }
 
-impl ParseCardError {
-    fn new(failed_string: String) -> Self {
-        ParseCardError { failed_string }
-    }
-}
-
-impl Display for ParseCardError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Failed to parse Card: {}", self.failed_string)
-    }
-}
-
-impl Error for ParseCardError {}
-
 impl TryFrom<char> for Card {
-    type Error = ParseCardError;
+    type Error = AocError;
 
     fn try_from(c: char) -> Result<Self, Self::Error> {
         Ok(match c {
@@ -66,7 +52,7 @@ impl TryFrom<char> for Card {
             'J' => Card::Jack,
             'T' => Card::Ten,
             c => match c.to_digit(10) {
-                None => return Err(ParseCardError::new(c.to_string())),
+                None | Some(0..=1) => return Err(card_error(c)),
                 Some(number_card) => {
                     assert!(number_card < 10);
                     let number_cards = [
@@ -86,34 +72,24 @@ impl TryFrom<char> for Card {
     }
 }
 
-impl TryFrom<char> for Card2 {
-    type Error = ParseCardError;
-
-    fn try_from(c: char) -> Result<Self, Self::Error> {
-        Ok(match c {
-            'A' => Card2::Ace,
-            'K' => Card2::King,
-            'Q' => Card2::Queen,
-            'J' => Card2::Joker,
-            'T' => Card2::Ten,
-            c => match c.to_digit(10) {
-                None => return Err(ParseCardError::new(c.to_string())),
-                Some(number_card) => {
-                    assert!(number_card < 10);
-                    let number_cards = [
-                        Card2::Two,
-                        Card2::Three,
-                        Card2::Four,
-                        Card2::Five,
-                        Card2::Six,
-                        Card2::Seven,
-                        Card2::Eight,
-                        Card2::Nine,
-                    ];
-                    number_cards[number_card as usize - 2]
-                }
-            },
-        })
+impl From<Card> for Card2 {
+    /// Jacks are jokers in part B.
+    fn from(card: Card) -> Self {
+        match card {
+            Card::Two => Card2::Two,
+            Card::Three => Card2::Three,
+            Card::Four => Card2::Four,
+            Card::Five => Card2::Five,
+            Card::Six => Card2::Six,
+            Card::Seven => Card2::Seven,
+            Card::Eight => Card2::Eight,
+            Card::Nine => Card2::Nine,
+            Card::Ten => Card2::Ten,
+            Card::Jack => Card2::Joker,
+            Card::Queen => Card2::Queen,
+            Card::King => Card2::King,
+            Card::Ace => Card2::Ace,
+        }
     }
 }
 
@@ -291,72 +267,76 @@ impl Ord for Hand2 {
     }
 }
 
-pub fn part_a(input: &str) -> Option<usize> {
-    let mut hand_list: Vec<(Hand, usize)> = input
-        .lines()
-        .map(|l| l.split_once(' ').unwrap())
-        .map(|(hand, bid)| (hand, bid.parse().unwrap()))
-        .map(|(hand, bid)| {
-            (
-                Hand {
-                    cards: hand.chars().map(|c| c.try_into().unwrap()).collect(),
-                },
-                bid,
-            )
-        })
-        .collect();
-
-    hand_list.sort_by(|(h1, _), (h2, _)| h1.cmp(h2));
-
-    Some(
-        hand_list
-            .iter()
-            .enumerate()
-            .fold(0, |acc, (i, (_, b))| acc + (i + 1) * b),
-    )
-}
-
-pub fn part_b(input: &str) -> Option<usize> {
-    let mut hand_list: Vec<(Hand2, usize)> = input
-        .lines()
-        .map(|l| l.split_once(' ').unwrap())
-        .map(|(hand, bid)| (hand, bid.parse().unwrap()))
-        .map(|(hand, bid)| {
-            (
-                Hand2 {
-                    cards: hand.chars().map(|c| c.try_into().unwrap()).collect(),
-                },
-                bid,
-            )
-        })
-        .collect();
-
-    hand_list.sort_by(|(h1, _), (h2, _)| h1.cmp(h2));
+pub struct Day07;
+
+#[aoc(year = 2023, day = 7)]
+impl Solution for Day07 {
+    /// The cards and the bid of every hand.
+    type Parsed = Vec<(Vec<Card>, usize)>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, AocError> {
+        input
+            .lines()
+            .map(|l| {
+                let (hand, bid) = l
+                    .split_once(' ')
+                    .ok_or_else(|| AocError::parse_at(input, l, "Expected a hand and a bid"))?;
+                let cards: Vec<Card> = hand
+                    .char_indices()
+                    .map(|(i, c)| Card::try_from(c).map_err(|why| why.at(input, &hand[i..])))
+                    .collect::<Result<_, _>>()?;
+                if cards.len() != 5 {
+                    return Err(AocError::parse_at(input, hand, "Expected 5 cards"));
+                }
+                let bid = bid.parse().map_err(|_| {
+                    AocError::parse_at(input, bid, format!("Failed to parse bid: {}", bid))
+                })?;
+                Ok((cards, bid))
+            })
+            .collect()
+    }
 
-    Some(
-        hand_list
+    fn part_a(hands: &Self::Parsed) -> Result<Option<usize>, AocError> {
+        let mut hand_list: Vec<(Hand, usize)> = hands
             .iter()
-            .enumerate()
-            .fold(0, |acc, (i, (_, b))| acc + (i + 1) * b),
-    )
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::fs::read_to_string;
-
-    #[test]
-    fn test_day_07_part_a() {
-        let input_a = read_to_string("./data/examples/day_07_a.txt").unwrap();
-        let result = part_a(input_a.as_str());
-        assert_eq!(result, Some(6440));
+            .map(|(cards, bid)| {
+                (
+                    Hand {
+                        cards: cards.clone(),
+                    },
+                    *bid,
+                )
+            })
+            .collect();
+
+        hand_list.sort_by(|(h1, _), (h2, _)| h1.cmp(h2));
+
+        Ok(Some(
+            hand_list
+                .iter()
+                .enumerate()
+                .fold(0, |acc, (i, (_, b))| acc + (i + 1) * b),
+        ))
     }
 
-    #[test]
-    fn test_day_07_part_b() {
-        let input_b = read_to_string("./data/examples/day_07_b.txt").unwrap();
-        let result = part_b(input_b.as_str());
-        assert_eq!(result, Some(5905));
+    fn part_b(hands: &Self::Parsed) -> Result<Option<usize>, AocError> {
+        let mut hand_list: Vec<(Hand2, usize)> = hands
+            .iter()
+            .map(|(cards, bid)| {
+                let cards = cards.iter().copied().map(Card2::from).collect();
+                (Hand2 { cards }, *bid)
+            })
+            .collect();
+
+        hand_list.sort_by(|(h1, _), (h2, _)| h1.cmp(h2));
+
+        Ok(Some(
+            hand_list
+                .iter()
+                .enumerate()
+                .fold(0, |acc, (i, (_, b))| acc + (i + 1) * b),
+        ))
     }
 }
@@ -0,0 +1,98 @@
+use crate::{AocError, Solution};
+use aoc_macros::aoc;
+use num::integer::lcm;
+use std::collections::HashMap;
+
+pub struct Day08;
+
+pub struct Network {
+    /// 0 for left and 1 for right.
+    instructions: Vec<usize>,
+    /// The left and right node after each node.
+    node_map: HashMap<String, [String; 2]>,
+}
+
+impl Network {
+    fn next_node(&self, node: &str, instruction: usize) -> Result<&String, AocError> {
+        self.node_map
+            .get(node)
+            .map(|next_nodes| &next_nodes[instruction])
+            .ok_or_else(|| AocError::invalid(format!("There is no node {}", node)))
+    }
+}
+
+#[aoc(year = 2023, day = 8)]
+impl Solution for Day08 {
+    type Parsed = Network;
+    type AnswerA = u32;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, AocError> {
+        let (instructions, nodes) = input
+            .split_once("\n\n")
+            .ok_or_else(|| AocError::parse(1, 1, "Expected instructions and nodes"))?;
+        let mut node_map = HashMap::new();
+        for node in nodes.lines() {
+            let (Some(name), Some(left), Some(right)) =
+                (node.get(0..3), node.get(7..10), node.get(12..15))
+            else {
+                return Err(AocError::parse_at(input, node, "Expected a node"));
+            };
+            node_map.insert(name.to_string(), [left.to_string(), right.to_string()]);
+        }
+        let instructions = instructions
+            .char_indices()
+            .map(|(i, c)| {
+                if c == 'L' {
+                    Ok(0)
+                } else if c == 'R' {
+                    Ok(1)
+                } else {
+                    Err(AocError::parse(
+                        1,
+                        i + 1,
+                        format!("Unknown instruction {}", c),
+                    ))
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if instructions.is_empty() {
+            return Err(AocError::parse(1, 1, "Expected instructions"));
+        }
+        Ok(Network {
+            instructions,
+            node_map,
+        })
+    }
+
+    fn part_a(network: &Self::Parsed) -> Result<Option<u32>, AocError> {
+        let mut instructions = network.instructions.iter().cycle();
+        let mut current_node = &String::from("AAA");
+        let mut steps = 0;
+        while current_node != &String::from("ZZZ") {
+            steps += 1;
+            current_node = network.next_node(current_node, *instructions.next().unwrap())?;
+        }
+
+        Ok(Some(steps))
+    }
+
+    fn part_b(network: &Self::Parsed) -> Result<Option<u64>, AocError> {
+        let instructions = network.instructions.iter().cycle();
+
+        let mut step_list = vec![];
+        for start_node in network.node_map.keys().filter(|k| k.ends_with('A')) {
+            let mut steps = 0;
+            let mut new_instructions = instructions.clone();
+            let mut current_node = start_node;
+            while !current_node.ends_with('Z') {
+                steps += 1;
+                current_node =
+                    network.next_node(current_node, *new_instructions.next().unwrap())?;
+            }
+            step_list.push(steps);
+        }
+
+        Ok(step_list.into_iter().reduce(lcm))
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{AocError, Solution};
+use aoc_macros::aoc;
+
+pub struct Day09;
+
+#[aoc(year = 2023, day = 9)]
+impl Solution for Day09 {
+    type Parsed = Vec<Vec<i64>>;
+    type AnswerA = i64;
+    type AnswerB = i64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, AocError> {
+        input
+            .lines()
+            .map(|l| {
+                l.split_whitespace()
+                    .map(|n| {
+                        n.parse().map_err(|_| {
+                            AocError::parse_at(input, n, format!("{} is not a number", n))
+                        })
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part_a(oasis_lines: &Self::Parsed) -> Result<Option<i64>, AocError> {
+        let mut sum = 0;
+        for report in oasis_lines {
+            let mut histories = vec![report.clone()];
+            while histories.last().unwrap().iter().sum::<i64>() != 0 {
+                let mut last_history_diffs = vec![];
+                for vals in histories.last().unwrap().as_slice().windows(2) {
+                    last_history_diffs.push(vals[1] - vals[0]);
+                }
+                histories.push(last_history_diffs);
+            }
+
+            histories.reverse();
+            let mut extrapolate_vals = vec![0];
+            for history in &histories[1..] {
+                extrapolate_vals.push(history.last().unwrap() + extrapolate_vals.last().unwrap());
+            }
+            sum += extrapolate_vals.last().unwrap();
+        }
+        Ok(Some(sum))
+    }
+
+    fn part_b(oasis_lines: &Self::Parsed) -> Result<Option<i64>, AocError> {
+        let mut sum = 0;
+        for report in oasis_lines {
+            let mut histories = vec![report.clone()];
+            while histories.last().unwrap().iter().sum::<i64>() != 0 {
+                let mut last_history_diffs = vec![];
+                for vals in histories.last().unwrap().as_slice().windows(2) {
+                    last_history_diffs.push(vals[1] - vals[0]);
+                }
+                histories.push(last_history_diffs);
+            }
+
+            histories.reverse();
+            let mut extrapolate_vals = vec![0];
+            for history in &histories[1..] {
+                extrapolate_vals.push(history.first().unwrap() - extrapolate_vals.last().unwrap());
+            }
+            sum += extrapolate_vals.last().unwrap();
+        }
+        Ok(Some(sum))
+    }
+}
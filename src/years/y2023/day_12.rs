@@ -1,3 +1,6 @@
+use crate::{AocError, Solution};
+use aoc_macros::aoc;
+
 fn get_valid_count(springs: &str, group_list: &Vec<usize>) -> u64 {
     let unknown_spring_count = springs.chars().filter(|c| c == &'?').count();
     let known_damaged_spring_count = springs.chars().filter(|c| c == &'#').count();
@@ -92,52 +95,54 @@ pub fn permutations<T: Clone + Ord>(s: &[T], subsize: usize) -> Permutations<T>
     }
 }
 
-pub fn part_a(input: &str) -> Option<u64> {
-    let rows = input
-        .lines()
-        .map(|l| l.split_once(' ').unwrap())
-        .collect::<Vec<_>>();
-    let mut sum = 0;
-    for row in rows {
-        let group_list = row
-            .1
-            .split(',')
-            .map(|s| s.parse().unwrap())
-            .collect::<Vec<usize>>();
-        sum += get_valid_count(row.0, &group_list);
-    }
-    Some(sum)
-}
+pub struct Day12;
 
-pub fn part_b(_input: &str) -> Option<u64> {
-    /*
-     * Determine the count of valid substitutions for the original string,
-     * and both for the original string with a "?" pre- and appended to it.
-     * It seems like valid_subs_orig * max(valid_subs_pre, valid_subs_app)^4
-     * lies pretty close to the solution. However, it fails to account for
-     * some edge cases at the boundaries between repetitions and I don't think
-     * this approach can account for those edge cases.
-     */
-
-    None
-}
+#[aoc(year = 2023, day = 12)]
+impl Solution for Day12 {
+    /// The springs and the sizes of the groups of damaged springs of each
+    /// row.
+    type Parsed = Vec<(String, Vec<usize>)>;
+    type AnswerA = u64;
+    type AnswerB = u64;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::fs::read_to_string;
+    fn parse(input: &str) -> Result<Self::Parsed, AocError> {
+        input
+            .lines()
+            .map(|l| {
+                let (springs, groups) = l
+                    .split_once(' ')
+                    .ok_or_else(|| AocError::parse_at(input, l, "Expected springs and groups"))?;
+                let group_list = groups
+                    .split(',')
+                    .map(|s| {
+                        s.parse().map_err(|_| {
+                            AocError::parse_at(input, s, format!("{} is not a group size", s))
+                        })
+                    })
+                    .collect::<Result<Vec<usize>, _>>()?;
+                Ok((springs.to_string(), group_list))
+            })
+            .collect()
+    }
 
-    #[test]
-    fn test_day_12_part_a() {
-        let input_a = read_to_string("./data/examples/day_12_a.txt").unwrap();
-        let result = part_a(input_a.as_str());
-        assert_eq!(result, Some(21));
+    fn part_a(rows: &Self::Parsed) -> Result<Option<u64>, AocError> {
+        let mut sum = 0;
+        for (springs, group_list) in rows {
+            sum += get_valid_count(springs, group_list);
+        }
+        Ok(Some(sum))
     }
 
-    #[test]
-    fn test_day_12_part_b() {
-        let input_b = read_to_string("./data/examples/day_12_b.txt").unwrap();
-        let result = part_b(input_b.as_str());
-        assert_eq!(result, Some(525152));
+    fn part_b(_rows: &Self::Parsed) -> Result<Option<u64>, AocError> {
+        /*
+         * Determine the count of valid substitutions for the original string,
+         * and both for the original string with a "?" pre- and appended to it.
+         * It seems like valid_subs_orig * max(valid_subs_pre, valid_subs_app)^4
+         * lies pretty close to the solution. However, it fails to account for
+         * some edge cases at the boundaries between repetitions and I don't think
+         * this approach can account for those edge cases.
+         */
+
+        Ok(None)
     }
 }
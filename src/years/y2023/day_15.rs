@@ -0,0 +1,126 @@
+use crate::{AocError, Solution};
+use aoc_macros::aoc;
+use std::hash::Hasher;
+
+struct Hash {
+    state: u64,
+}
+
+impl Hash {
+    fn new() -> Self {
+        Hash { state: 0 }
+    }
+}
+
+impl Hasher for Hash {
+    fn finish(&self) -> u64 {
+        self.state
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for b in bytes {
+            self.write_u8(*b);
+        }
+    }
+
+    fn write_u8(&mut self, i: u8) {
+        self.state += i as u64;
+        self.state *= 17;
+        self.state %= 256;
+    }
+}
+
+pub enum Operation {
+    Remove,
+    Insert { focal_length: usize },
+}
+
+pub struct Step {
+    text: String,
+    label: String,
+    operation: Operation,
+}
+
+pub struct Day15;
+
+#[aoc(year = 2023, day = 15)]
+impl Solution for Day15 {
+    type Parsed = Vec<Step>;
+    type AnswerA = u64;
+    type AnswerB = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, AocError> {
+        input
+            .trim()
+            .split(',')
+            .map(|step| {
+                let (label, operation) = if let Some(label) = step.strip_suffix('-') {
+                    (label, Operation::Remove)
+                } else {
+                    let (label, focal_length) = step.split_once('=').ok_or_else(|| {
+                        AocError::parse_at(input, step, "Expected a `-` or `=` operation")
+                    })?;
+                    let focal_length = focal_length.parse().map_err(|_| {
+                        AocError::parse_at(
+                            input,
+                            focal_length,
+                            format!("{} is not a focal length", focal_length),
+                        )
+                    })?;
+                    (label, Operation::Insert { focal_length })
+                };
+                Ok(Step {
+                    text: step.to_string(),
+                    label: label.to_string(),
+                    operation,
+                })
+            })
+            .collect()
+    }
+
+    fn part_a(steps: &Self::Parsed) -> Result<Option<u64>, AocError> {
+        let mut hash_sum = 0;
+        for step in steps {
+            let mut hasher = Hash::new();
+            hasher.write(step.text.as_bytes());
+            hash_sum += hasher.finish();
+        }
+
+        Ok(Some(hash_sum))
+    }
+
+    fn part_b(steps: &Self::Parsed) -> Result<Option<usize>, AocError> {
+        let mut boxes: Vec<Vec<(&str, usize)>> = vec![vec![]; 256];
+        for step in steps {
+            let label = step.label.as_str();
+            let mut hasher = Hash::new();
+            hasher.write(label.as_bytes());
+            let target_box = hasher.finish() as usize;
+            match step.operation {
+                Operation::Remove => {
+                    if let Some(target_pos) = boxes[target_box].iter().position(|i| i.0 == label) {
+                        boxes[target_box].remove(target_pos);
+                    }
+                }
+                Operation::Insert { focal_length } => {
+                    if let Some(target_pos) = boxes[target_box].iter().position(|i| i.0 == label) {
+                        boxes[target_box][target_pos] = (label, focal_length);
+                    } else {
+                        boxes[target_box].push((label, focal_length));
+                    }
+                }
+            }
+        }
+
+        let mut focusing_power = 0;
+        for (box_index, b) in boxes.into_iter().enumerate() {
+            focusing_power += b
+                .into_iter()
+                .enumerate()
+                .map(|(slot, lens)| (box_index + 1) * (slot + 1) * lens.1)
+                .sum::<usize>();
+        }
+
+        Ok(Some(focusing_power))
+    }
+}
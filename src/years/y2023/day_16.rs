@@ -1,8 +1,10 @@
+use crate::{AocError, Solution};
+use aoc_macros::aoc;
 use itertools::Itertools;
 use std::collections::HashSet;
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
-enum BeamDirection {
+pub enum BeamDirection {
     North,
     East,
     South,
@@ -141,76 +143,59 @@ fn calculate_energized_tiles(
         .count()
 }
 
-pub fn part_a(input: &str) -> Option<usize> {
-    let mut tiles: Vec<Vec<(char, HashSet<BeamDirection>)>> = input
-        .lines()
-        .map(|p| p.chars().map(|c| (c, HashSet::new())).collect())
-        .collect();
-
-    let old_row_length = tiles[0].len();
-    let bounding_row = vec![('x', HashSet::new()); old_row_length];
-    tiles.insert(0, bounding_row.clone());
-    tiles.push(bounding_row);
-    tiles.iter_mut().for_each(|r| {
-        r.insert(0, ('x', HashSet::new()));
-        r.push(('x', HashSet::new()));
-    });
-
-    Some(calculate_energized_tiles(
-        &tiles,
-        (1, 1),
-        BeamDirection::East,
-    ))
-}
-
-pub fn part_b(input: &str) -> Option<usize> {
-    let mut tiles: Vec<Vec<(char, HashSet<BeamDirection>)>> = input
-        .lines()
-        .map(|p| p.chars().map(|c| (c, HashSet::new())).collect())
-        .collect();
-
-    let old_row_length = tiles[0].len();
-    let bounding_row = vec![('x', HashSet::new()); old_row_length];
-    tiles.insert(0, bounding_row.clone());
-    tiles.push(bounding_row);
-    tiles.iter_mut().for_each(|r| {
-        r.insert(0, ('x', HashSet::new()));
-        r.push(('x', HashSet::new()));
-    });
-
-    let mut initial_configs = vec![];
-    (1..tiles.len() - 1).for_each(|i| {
-        initial_configs.push(((i, 1), BeamDirection::East));
-        initial_configs.push(((i, tiles[0].len() - 2), BeamDirection::West));
-    });
-
-    (1..tiles[0].len() - 1).for_each(|i| {
-        initial_configs.push(((1, i), BeamDirection::South));
-        initial_configs.push(((tiles.len() - 2, i), BeamDirection::North));
-    });
-
-    initial_configs
-        .into_iter()
-        .map(|cfg| calculate_energized_tiles(&tiles, cfg.0, cfg.1))
-        .max()
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::fs::read_to_string;
+pub struct Day16;
+
+#[aoc(year = 2023, day = 16)]
+impl Solution for Day16 {
+    /// The tiles and the directions of the beams through them, with a border
+    /// of `x` around them.
+    type Parsed = Vec<Vec<(char, HashSet<BeamDirection>)>>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, AocError> {
+        let mut tiles: Vec<Vec<(char, HashSet<BeamDirection>)>> = input
+            .lines()
+            .map(|p| p.chars().map(|c| (c, HashSet::new())).collect())
+            .collect();
+
+        let Some(first_row) = tiles.first() else {
+            return Err(AocError::parse(1, 1, "Expected a contraption"));
+        };
+        let old_row_length = first_row.len();
+        let bounding_row = vec![('x', HashSet::new()); old_row_length];
+        tiles.insert(0, bounding_row.clone());
+        tiles.push(bounding_row);
+        tiles.iter_mut().for_each(|r| {
+            r.insert(0, ('x', HashSet::new()));
+            r.push(('x', HashSet::new()));
+        });
+        Ok(tiles)
+    }
 
-    #[test]
-    fn test_day_16_part_a() {
-        let input_a = read_to_string("./data/examples/day_16_a.txt").unwrap();
-        let result = part_a(input_a.as_str());
-        assert_eq!(result, Some(46));
+    fn part_a(tiles: &Self::Parsed) -> Result<Option<usize>, AocError> {
+        Ok(Some(calculate_energized_tiles(
+            tiles,
+            (1, 1),
+            BeamDirection::East,
+        )))
     }
 
-    #[test]
-    fn test_day_16_part_b() {
-        let input_b = read_to_string("./data/examples/day_16_b.txt").unwrap();
-        let result = part_b(input_b.as_str());
-        assert_eq!(result, Some(51));
+    fn part_b(tiles: &Self::Parsed) -> Result<Option<usize>, AocError> {
+        let mut initial_configs = vec![];
+        (1..tiles.len() - 1).for_each(|i| {
+            initial_configs.push(((i, 1), BeamDirection::East));
+            initial_configs.push(((i, tiles[0].len() - 2), BeamDirection::West));
+        });
+
+        (1..tiles[0].len() - 1).for_each(|i| {
+            initial_configs.push(((1, i), BeamDirection::South));
+            initial_configs.push(((tiles.len() - 2, i), BeamDirection::North));
+        });
+
+        Ok(initial_configs
+            .into_iter()
+            .map(|cfg| calculate_energized_tiles(tiles, cfg.0, cfg.1))
+            .max())
     }
 }
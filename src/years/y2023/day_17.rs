@@ -0,0 +1,37 @@
+use crate::{AocError, Solution};
+use aoc_macros::aoc;
+
+pub struct Day17;
+
+#[aoc(year = 2023, day = 17)]
+impl Solution for Day17 {
+    /// The heat loss of every block.
+    type Parsed = Vec<Vec<u32>>;
+    type AnswerA = u32;
+    type AnswerB = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed, AocError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(x, c)| {
+                        c.to_digit(10).ok_or_else(|| {
+                            AocError::parse(y + 1, x + 1, format!("{} is not a heat loss", c))
+                        })
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part_a(_heat_loss: &Self::Parsed) -> Result<Option<u32>, AocError> {
+        Ok(None)
+    }
+
+    fn part_b(_heat_loss: &Self::Parsed) -> Result<Option<u32>, AocError> {
+        Ok(None)
+    }
+}
@@ -0,0 +1,165 @@
+use crate::{AocError, Solution};
+use aoc_macros::aoc;
+use std::collections::HashSet;
+
+fn floodfill(pos: (isize, isize), boundary: &HashSet<(isize, isize)>) -> HashSet<(isize, isize)> {
+    let mut filled = boundary.clone();
+    let mut stack = Vec::new();
+    stack.push(pos);
+
+    while let Some((y, x)) = stack.pop() {
+        if !filled.contains(&(y, x)) {
+            filled.insert((y, x));
+            stack.push((y, x + 1));
+            stack.push((y, x - 1));
+            stack.push((y + 1, x));
+            stack.push((y - 1, x));
+        }
+    }
+
+    filled
+}
+
+/// Parses a step like `R 6` of the dig plan.
+fn parse_step(input: &str, line: &str) -> Result<(char, isize), AocError> {
+    let mut i = line.split_whitespace();
+    let (Some(direction), Some(length)) = (i.next(), i.next()) else {
+        return Err(AocError::parse_at(
+            input,
+            line,
+            "Expected a direction and a length",
+        ));
+    };
+    let direction = match direction {
+        "U" => 'U',
+        "D" => 'D',
+        "L" => 'L',
+        "R" => 'R',
+        _ => {
+            return Err(AocError::parse_at(
+                input,
+                direction,
+                format!("{} is not a direction", direction),
+            ))
+        }
+    };
+    let length = length
+        .parse()
+        .map_err(|_| AocError::parse_at(input, length, format!("{} is not a length", length)))?;
+    Ok((direction, length))
+}
+
+/// Parses the step hidden in the color like `(#70c710)` of a step of the dig
+/// plan.
+fn parse_color_step(input: &str, line: &str) -> Result<(char, isize), AocError> {
+    let Some(i) = line
+        .rsplit_once('#')
+        .and_then(|(_, color)| color.strip_suffix(')'))
+        .filter(|i| i.len() == 6 && i.is_char_boundary(5))
+    else {
+        return Err(AocError::parse_at(input, line, "Expected a color"));
+    };
+    let l = isize::from_str_radix(&i[..5], 16)
+        .map_err(|_| AocError::parse_at(input, i, format!("{} is not a hex length", &i[..5])))?;
+    let c = &i[5..];
+
+    match c {
+        "0" => Ok(('R', l)),
+        "1" => Ok(('D', l)),
+        "2" => Ok(('L', l)),
+        "3" => Ok(('U', l)),
+        _ => Err(AocError::parse_at(
+            input,
+            c,
+            format!("{} is not a direction", c),
+        )),
+    }
+}
+
+pub struct Day18;
+
+#[aoc(year = 2023, day = 18)]
+impl Solution for Day18 {
+    /// Every step of the dig plan, and the one hidden in its color.
+    type Parsed = Vec<((char, isize), (char, isize))>;
+    type AnswerA = usize;
+    type AnswerB = isize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, AocError> {
+        input
+            .lines()
+            .map(|line| Ok((parse_step(input, line)?, parse_color_step(input, line)?)))
+            .collect()
+    }
+
+    fn part_a(dig_plan: &Self::Parsed) -> Result<Option<usize>, AocError> {
+        let mut current_coord = (0, 0);
+        let mut coords = HashSet::from([current_coord]);
+
+        for &(instruction, _) in dig_plan {
+            for _ in 0..instruction.1 {
+                match instruction.0 {
+                    'U' => {
+                        current_coord.0 -= 1;
+                    }
+                    'D' => {
+                        current_coord.0 += 1;
+                    }
+                    'L' => {
+                        current_coord.1 -= 1;
+                    }
+                    'R' => {
+                        current_coord.1 += 1;
+                    }
+                    _ => unreachable!(),
+                }
+                coords.insert(current_coord);
+            }
+        }
+
+        let mut interior = *coords
+            .iter()
+            .find(|c1| {
+                coords
+                    .iter()
+                    .filter(|c2| c1.0 == c2.0)
+                    .all(|c2| c2.1 <= c1.1 && c2.1 != c1.1 - 1)
+            })
+            .ok_or_else(|| AocError::invalid("The trench has no interior"))?;
+        interior.1 -= 1;
+
+        Ok(Some(floodfill(interior, &coords).len()))
+    }
+
+    fn part_b(dig_plan: &Self::Parsed) -> Result<Option<isize>, AocError> {
+        let mut current_coord = (0, 0);
+        let mut coords = vec![current_coord];
+
+        let mut perimeter_sum = 0;
+        for &(_, instruction) in dig_plan {
+            perimeter_sum += instruction.1;
+            match instruction.0 {
+                'U' => {
+                    current_coord.0 -= instruction.1;
+                }
+                'D' => {
+                    current_coord.0 += instruction.1;
+                }
+                'L' => {
+                    current_coord.1 -= instruction.1;
+                }
+                'R' => {
+                    current_coord.1 += instruction.1;
+                }
+                _ => unreachable!(),
+            }
+            coords.push(current_coord);
+        }
+
+        let shoelace = coords
+            .windows(2)
+            .map(|w| w[0].1 * w[1].0 - w[1].1 * w[0].0)
+            .sum::<isize>();
+        Ok(Some(shoelace / 2 + perimeter_sum / 2 + 1))
+    }
+}
@@ -0,0 +1,201 @@
+use crate::{AocError, Solution};
+use aoc_macros::aoc;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Workflow {
+    name: String,
+    steps: Vec<WorkflowStep>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct ConditionalWorkflowStep {
+    category: Category,
+    comparison: Comparison,
+    value: u32,
+    send_to_workflow: String,
+}
+
+impl WorkflowStep {
+    fn get_next_workflow(&self, part: &Part) -> Option<String> {
+        match self {
+            WorkflowStep::Conditional(conditional) => {
+                let value_to_compare = match conditional.category {
+                    Category::X => part.x,
+                    Category::M => part.m,
+                    Category::A => part.a,
+                    Category::S => part.s,
+                };
+
+                let send = if conditional.comparison == Comparison::Greater {
+                    value_to_compare > conditional.value
+                } else {
+                    value_to_compare < conditional.value
+                };
+
+                if send {
+                    Some(conditional.send_to_workflow.clone())
+                } else {
+                    None
+                }
+            }
+            WorkflowStep::Unconditional(u) => Some(u.to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum WorkflowStep {
+    Conditional(ConditionalWorkflowStep),
+    Unconditional(String),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+enum Comparison {
+    Greater,
+    Lesser,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+enum Category {
+    X,
+    M,
+    A,
+    S,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+struct Part {
+    x: u32,
+    m: u32,
+    a: u32,
+    s: u32,
+}
+
+/// The workflows and the parts to sort with them.
+pub struct System {
+    workflows: Vec<Workflow>,
+    parts: Vec<Part>,
+}
+
+fn parse_step(input: &str, step: &str) -> Result<WorkflowStep, AocError> {
+    if !step.contains(':') {
+        return Ok(WorkflowStep::Unconditional(step.to_string()));
+    }
+    let (comparison, cmp_symbol) = if step.contains('<') {
+        (Comparison::Lesser, "<")
+    } else {
+        (Comparison::Greater, ">")
+    };
+    let invalid_step = || AocError::parse_at(input, step, format!("{} is not a valid step", step));
+    let (category_string, rest) = step.split_once(cmp_symbol).ok_or_else(invalid_step)?;
+    let category = match category_string {
+        "x" => Category::X,
+        "m" => Category::M,
+        "a" => Category::A,
+        "s" => Category::S,
+        _ => return Err(invalid_step()),
+    };
+    let (value_string, next_workflow) = rest.split_once(':').ok_or_else(invalid_step)?;
+    Ok(WorkflowStep::Conditional(ConditionalWorkflowStep {
+        category,
+        comparison,
+        value: value_string.parse().map_err(|_| invalid_step())?,
+        send_to_workflow: next_workflow.to_string(),
+    }))
+}
+
+fn parse_part(input: &str, part: &str) -> Result<Part, AocError> {
+    let invalid_part = || AocError::parse_at(input, part, "Expected a part like {x=1,m=2,a=3,s=4}");
+    let stripped_part = part
+        .strip_prefix('{')
+        .and_then(|part| part.strip_suffix('}'))
+        .ok_or_else(invalid_part)?;
+    let attributes = stripped_part
+        .split(',')
+        .map(|attr| {
+            attr.split_once('=')
+                .and_then(|(_, value)| value.parse().ok())
+                .ok_or_else(invalid_part)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let [x, m, a, s] = attributes[..] else {
+        return Err(invalid_part());
+    };
+    Ok(Part { x, m, a, s })
+}
+
+pub struct Day19;
+
+#[aoc(year = 2023, day = 19)]
+impl Solution for Day19 {
+    type Parsed = System;
+    type AnswerA = u32;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, AocError> {
+        let (workflow_string, parts_string) = input
+            .split_once("\n\n")
+            .ok_or_else(|| AocError::parse(1, 1, "Expected workflows and parts"))?;
+
+        let mut workflows = vec![];
+        for workflow in workflow_string.lines() {
+            let (name, steps) = workflow
+                .split_once('{')
+                .and_then(|(name, rest)| Some((name, rest.strip_suffix('}')?)))
+                .ok_or_else(|| AocError::parse_at(input, workflow, "Expected a workflow"))?;
+            let step_list = steps
+                .split(',')
+                .map(|step| parse_step(input, step))
+                .collect::<Result<_, _>>()?;
+            let workflow = Workflow {
+                name: name.to_string(),
+                steps: step_list,
+            };
+            workflows.push(workflow)
+        }
+
+        let parts = parts_string
+            .lines()
+            .map(|part| parse_part(input, part))
+            .collect::<Result<_, _>>()?;
+
+        Ok(System { workflows, parts })
+    }
+
+    fn part_a(system: &Self::Parsed) -> Result<Option<u32>, AocError> {
+        let mut sum = 0;
+        for part in &system.parts {
+            let mut current_workflow_name = String::from("in");
+
+            while !["A", "R"].contains(&current_workflow_name.as_str()) {
+                let current_workflow = system
+                    .workflows
+                    .iter()
+                    .find(|w| w.name == current_workflow_name)
+                    .ok_or_else(|| {
+                        AocError::invalid(format!("There is no workflow {}", current_workflow_name))
+                    })?;
+                current_workflow_name = current_workflow
+                    .steps
+                    .iter()
+                    .find_map(|step| step.get_next_workflow(part))
+                    .ok_or_else(|| {
+                        AocError::invalid(format!(
+                            "Workflow {} does not send every part on",
+                            current_workflow.name
+                        ))
+                    })?;
+            }
+
+            if current_workflow_name == "A" {
+                sum += part.x + part.m + part.a + part.s;
+            }
+        }
+
+        Ok(Some(sum))
+    }
+
+    fn part_b(_system: &Self::Parsed) -> Result<Option<u64>, AocError> {
+        Ok(None)
+    }
+}
@@ -1,3 +1,5 @@
+use crate::{AocError, Solution};
+use aoc_macros::aoc;
 use std::collections::VecDeque;
 use std::ops::Not;
 use std::{cell::RefCell, rc::Rc};
@@ -9,16 +11,6 @@ trait Module: std::fmt::Debug {
     fn get_name(&self) -> String;
     fn add_child(&mut self, child: Rc<RefCell<dyn Module>>);
     fn add_parent(&mut self, parent: Rc<RefCell<dyn Module>>);
-    fn get_children(&self) -> &Vec<Rc<RefCell<dyn Module>>>;
-    fn get_module_type(&self) -> ModuleType;
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum ModuleType {
-    Broadcast,
-    Flipflop,
-    Conjunction,
-    Sink,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -106,14 +98,6 @@ impl Module for Broadcast {
     }
 
     fn add_parent(&mut self, _parent: Rc<RefCell<dyn Module>>) {}
-
-    fn get_children(&self) -> &Vec<Rc<RefCell<dyn Module>>> {
-        &self.child_modules
-    }
-
-    fn get_module_type(&self) -> ModuleType {
-        ModuleType::Broadcast
-    }
 }
 
 impl Module for Sink {
@@ -137,15 +121,7 @@ impl Module for Sink {
         Vec::new()
     }
 
-    fn get_children(&self) -> &Vec<Rc<RefCell<dyn Module>>> {
-        panic!("Unable to get children of sink module {}", self.name)
-    }
-
     fn process_pulse(&mut self, _pulse: Pulse) {}
-
-    fn get_module_type(&self) -> ModuleType {
-        ModuleType::Sink
-    }
 }
 
 impl Module for FlipFlop {
@@ -183,14 +159,6 @@ impl Module for FlipFlop {
     }
 
     fn add_parent(&mut self, _parent: Rc<RefCell<dyn Module>>) {}
-
-    fn get_children(&self) -> &Vec<Rc<RefCell<dyn Module>>> {
-        &self.child_modules
-    }
-
-    fn get_module_type(&self) -> ModuleType {
-        ModuleType::Flipflop
-    }
 }
 
 impl Module for Conjunction {
@@ -230,127 +198,123 @@ impl Module for Conjunction {
     fn add_parent(&mut self, parent: Rc<RefCell<dyn Module>>) {
         self.parent_modules.push(parent);
     }
-
-    fn get_children(&self) -> &Vec<Rc<RefCell<dyn Module>>> {
-        &self.child_modules
-    }
-
-    fn get_module_type(&self) -> ModuleType {
-        ModuleType::Conjunction
-    }
 }
 
-pub fn part_a(input: &str) -> Option<u64> {
-    let lines = input.lines();
-    let mut modules: Vec<Rc<RefCell<dyn Module>>> = Vec::new();
-
-    for line in lines.clone() {
-        let (description, _) = line.split_once(" -> ").unwrap();
-
-        let module = match line.chars().next().unwrap() {
-            '%' => Rc::new(RefCell::new(FlipFlop {
-                should_propagate: true,
-                name: description.strip_prefix('%').unwrap().to_string(),
-                state: State::Off,
-                child_modules: Vec::new(),
-            })) as _,
-            '&' => Rc::new(RefCell::new(Conjunction {
-                name: description.strip_prefix('&').unwrap().to_string(),
-                state: State::Off,
-                child_modules: Vec::new(),
-                parent_modules: Vec::new(),
-            })) as _,
-            _ => Rc::new(RefCell::new(Broadcast {
-                child_modules: Vec::new(),
-            })) as _,
-        };
-
-        modules.push(module);
-    }
+pub struct ModuleDescription {
+    /// `%`, `&` or `b` for the broadcaster.
+    kind: char,
+    name: String,
+    child_names: Vec<String>,
+}
 
-    for line in lines {
-        let (description, children) = line.split_once(" -> ").unwrap();
-        let name = description
-            .strip_prefix('%')
-            .or(description.strip_prefix('&'))
-            .unwrap_or(description);
+pub struct Day20;
+
+#[aoc(year = 2023, day = 20)]
+impl Solution for Day20 {
+    type Parsed = Vec<ModuleDescription>;
+    type AnswerA = u64;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, AocError> {
+        input
+            .lines()
+            .map(|line| {
+                let (description, children) = line
+                    .split_once(" -> ")
+                    .ok_or_else(|| AocError::parse_at(input, line, "Expected a module"))?;
+                let (kind, name) = match description.strip_prefix('%') {
+                    Some(name) => ('%', name),
+                    None => match description.strip_prefix('&') {
+                        Some(name) => ('&', name),
+                        None => ('b', description),
+                    },
+                };
+                Ok(ModuleDescription {
+                    kind,
+                    name: name.to_string(),
+                    child_names: children.split(", ").map(str::to_string).collect(),
+                })
+            })
+            .collect()
+    }
+
+    fn part_a(descriptions: &Self::Parsed) -> Result<Option<u64>, AocError> {
+        let mut modules: Vec<Rc<RefCell<dyn Module>>> = Vec::new();
+
+        for description in descriptions {
+            let module = match description.kind {
+                '%' => Rc::new(RefCell::new(FlipFlop {
+                    should_propagate: true,
+                    name: description.name.clone(),
+                    state: State::Off,
+                    child_modules: Vec::new(),
+                })) as _,
+                '&' => Rc::new(RefCell::new(Conjunction {
+                    name: description.name.clone(),
+                    state: State::Off,
+                    child_modules: Vec::new(),
+                    parent_modules: Vec::new(),
+                })) as _,
+                _ => Rc::new(RefCell::new(Broadcast {
+                    child_modules: Vec::new(),
+                })) as _,
+            };
 
-        let current_module = modules
-            .iter()
-            .find(|module| module.borrow().get_name() == *name)
-            .unwrap()
-            .clone();
+            modules.push(module);
+        }
 
-        for child_name in children.split(", ") {
-            if let Some(child) = modules
-                .iter()
-                .find(|module| module.borrow().get_name() == *child_name)
-            {
-                current_module.borrow_mut().add_child(child.clone());
-                child.borrow_mut().add_parent(current_module.clone());
-            } else {
-                let sink = Rc::new(RefCell::new(Sink {
-                    name: child_name.to_string(),
-                }));
-
-                current_module.borrow_mut().add_child(sink.clone());
-                modules.push(sink);
+        for (description, current_module) in descriptions.iter().zip(modules.clone()) {
+            for child_name in &description.child_names {
+                if let Some(child) = modules
+                    .iter()
+                    .find(|module| module.borrow().get_name() == *child_name)
+                {
+                    current_module.borrow_mut().add_child(child.clone());
+                    child.borrow_mut().add_parent(current_module.clone());
+                } else {
+                    let sink = Rc::new(RefCell::new(Sink {
+                        name: child_name.to_string(),
+                    }));
+
+                    current_module.borrow_mut().add_child(sink.clone());
+                    modules.push(sink);
+                }
             }
         }
-    }
 
-    let broadcaster = modules
-        .iter()
-        .find(|module| module.borrow().get_name() == *"broadcaster")
-        .unwrap()
-        .clone();
-    let mut pulse_queue = VecDeque::new();
-
-    let n = 1000;
-    let mut high_pulses = 0;
-    let mut low_pulses = n;
-    for _ in 0..n {
-        // println!("{i}th press");
-        for pulse in broadcaster.borrow().send_pulses() {
-            pulse_queue.push_back(pulse);
-        }
-        while let Some(next_pulse) = pulse_queue.pop_front() {
-            if next_pulse.0 == Pulse::High {
-                high_pulses += 1
-            } else {
-                low_pulses += 1
-            };
-            next_pulse.1.borrow_mut().process_pulse(next_pulse.0);
-            for pulse in next_pulse.1.borrow().send_pulses() {
+        let broadcaster = modules
+            .iter()
+            .find(|module| module.borrow().get_name() == *"broadcaster")
+            .ok_or_else(|| AocError::invalid("There is no broadcaster"))?
+            .clone();
+        let mut pulse_queue = VecDeque::new();
+
+        let n = 1000;
+        let mut high_pulses = 0;
+        let mut low_pulses = n;
+        for _ in 0..n {
+            // println!("{i}th press");
+            for pulse in broadcaster.borrow().send_pulses() {
                 pulse_queue.push_back(pulse);
             }
+            while let Some(next_pulse) = pulse_queue.pop_front() {
+                if next_pulse.0 == Pulse::High {
+                    high_pulses += 1
+                } else {
+                    low_pulses += 1
+                };
+                next_pulse.1.borrow_mut().process_pulse(next_pulse.0);
+                for pulse in next_pulse.1.borrow().send_pulses() {
+                    pulse_queue.push_back(pulse);
+                }
+            }
         }
-    }
-
-    // println!("High: {} Low: {}", high_pulses, low_pulses);
-    Some(high_pulses * low_pulses)
-}
-
-pub fn part_b(_input: &str) -> Option<u64> {
-    None
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::fs::read_to_string;
 
-    #[test]
-    fn test_day_20_part_a() {
-        let input_a = read_to_string("./data/examples/day_20_a.txt").unwrap();
-        let result = part_a(input_a.as_str());
-        assert_eq!(result, Some(11687500));
+        // println!("High: {} Low: {}", high_pulses, low_pulses);
+        Ok(Some(high_pulses * low_pulses))
     }
 
-    #[test]
-    fn test_day_20_part_b() {
-        let input_b = read_to_string("./data/examples/day_20_b.txt").unwrap();
-        let result = part_b(input_b.as_str());
-        assert_eq!(result, None);
+    fn part_b(_descriptions: &Self::Parsed) -> Result<Option<u64>, AocError> {
+        Ok(None)
     }
 }
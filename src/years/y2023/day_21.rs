@@ -0,0 +1,97 @@
+use crate::{AocError, Solution};
+use aoc_macros::aoc;
+use std::collections::{HashSet, VecDeque};
+
+pub struct Garden {
+    /// With a border of rocks around it.
+    map: Vec<Vec<char>>,
+    start: (usize, usize),
+}
+
+pub struct Day21;
+
+#[aoc(year = 2023, day = 21)]
+impl Solution for Day21 {
+    type Parsed = Garden;
+    type AnswerA = usize;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, AocError> {
+        let mut map: Vec<Vec<char>> = input.lines().map(|l| l.chars().collect()).collect();
+        let Some(first_row) = map.first() else {
+            return Err(AocError::parse(1, 1, "Expected a garden"));
+        };
+        let old_row_length = first_row.len();
+        let bounding_row = vec!['#'; old_row_length];
+        map.insert(0, bounding_row.clone());
+        map.push(bounding_row);
+        map.iter_mut().for_each(|r| {
+            r.insert(0, '#');
+            r.push('#');
+        });
+
+        let y_start = map
+            .iter()
+            .position(|r| r.contains(&'S'))
+            .ok_or_else(|| AocError::invalid("There is no starting position S"))?;
+        let x_start = map[y_start].iter().position(|c| *c == 'S').unwrap();
+        Ok(Garden {
+            map,
+            start: (y_start, x_start),
+        })
+    }
+
+    fn part_a(garden: &Self::Parsed) -> Result<Option<usize>, AocError> {
+        let mut garden_map: Vec<Vec<(char, HashSet<u64>)>> = garden
+            .map
+            .iter()
+            .map(|r| r.iter().map(|&c| (c, HashSet::new())).collect())
+            .collect();
+        let (y_start, x_start) = garden.start;
+
+        garden_map[y_start][x_start].1.insert(0);
+
+        let mut queue = VecDeque::from([(y_start, x_start)]);
+        while let Some(current_position) = queue.pop_front() {
+            let neighbor_positions = [
+                (current_position.0 - 1, current_position.1),
+                (current_position.0 + 1, current_position.1),
+                (current_position.0, current_position.1 + 1),
+                (current_position.0, current_position.1 - 1),
+            ]
+            .into_iter()
+            .filter(|pos| garden_map[pos.0][pos.1].0 != '#')
+            .collect::<Vec<_>>();
+
+            for neigbor_position in neighbor_positions {
+                let modified_own_step_list = garden_map[current_position.0][current_position.1]
+                    .1
+                    .clone()
+                    .into_iter()
+                    .filter_map(|dst| if dst < 64 { Some(dst + 1) } else { None })
+                    .collect::<HashSet<_>>();
+
+                let current_neighbor_step_list =
+                    &garden_map[neigbor_position.0][neigbor_position.1].1;
+                if !modified_own_step_list.is_subset(current_neighbor_step_list) {
+                    garden_map[neigbor_position.0][neigbor_position.1]
+                        .1
+                        .extend(modified_own_step_list.iter());
+                    queue.push_back(neigbor_position);
+                }
+            }
+        }
+
+        Ok(Some(
+            garden_map
+                .into_iter()
+                .flatten()
+                .filter(|pos| pos.1.contains(&64))
+                .count(),
+        ))
+    }
+
+    fn part_b(_garden: &Self::Parsed) -> Result<Option<u64>, AocError> {
+        Ok(None)
+    }
+}
@@ -0,0 +1,103 @@
+//! Helpers for the integration tests that need a data directory or a puzzle
+//! server.
+
+use advent_of_code_rust_criterion::config;
+use std::fs::remove_dir_all;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, MutexGuard, Once, PoisonError, Weak};
+use std::thread;
+
+static SHARED: Mutex<Weak<DataDir>> = Mutex::new(Weak::new());
+
+fn shared() -> MutexGuard<'static, Weak<DataDir>> {
+    SHARED.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// A data directory in the temp dir, removed again once the last test holding
+/// it is done.
+pub struct DataDir(PathBuf);
+
+impl Drop for DataDir {
+    fn drop(&mut self) {
+        // A test that started in the meantime may have taken the directory
+        // over, it is removed once that one is done.
+        let shared = shared();
+        if shared.strong_count() == 0 {
+            let _ = remove_dir_all(&self.0);
+        }
+    }
+}
+
+/// Every test in a test binary shares one data directory, so each uses days
+/// of its own.
+pub fn data_dir(name: &str) -> Arc<DataDir> {
+    static INIT: Once = Once::new();
+    let path = std::env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()));
+    INIT.call_once(|| assert!(config::set_data_dir(path.clone())));
+
+    let mut shared = shared();
+    shared.upgrade().unwrap_or_else(|| {
+        let _ = remove_dir_all(&path);
+        let data_dir = Arc::new(DataDir(path));
+        *shared = Arc::downgrade(&data_dir);
+        data_dir
+    })
+}
+
+/// A stand-in for the puzzle server on a free local port.
+pub struct MockServer {
+    pub base_url: String,
+    requests: Arc<Mutex<Vec<String>>>,
+}
+
+impl MockServer {
+    /// Answers every request with `200 OK` and the body `respond` makes of
+    /// the raw request.
+    pub fn start(mut respond: impl FnMut(&str) -> String + Send + 'static) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(vec![]));
+        let seen = Arc::clone(&requests);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut reader = BufReader::new(stream.unwrap());
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some((name, value)) = line.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            content_length = value.trim().parse().unwrap();
+                        }
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+                }
+                let mut body = vec![0; content_length];
+                reader.read_exact(&mut body).unwrap();
+                request.push_str(&String::from_utf8(body).unwrap());
+
+                let response = respond(&request);
+                seen.lock().unwrap().push(request);
+                write!(
+                    reader.get_mut(),
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    response.len(),
+                    response
+                )
+                .unwrap();
+            }
+        });
+        MockServer { base_url, requests }
+    }
+
+    /// Every request received so far, with its head and body.
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+}
@@ -0,0 +1,89 @@
+mod common;
+
+use advent_of_code_rust_criterion::client::Client;
+use advent_of_code_rust_criterion::download::{download_input, read_downloads, Download};
+use advent_of_code_rust_criterion::{config, input_path, Event};
+use chrono::{DateTime, Utc};
+use common::{DataDir, MockServer};
+use std::fs::read_to_string;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// The path of a raw request.
+fn path(request: &str) -> &str {
+    request.split(' ').nth(1).unwrap()
+}
+
+/// A puzzle server that answers every `GET` with the path that was requested.
+fn input_server() -> MockServer {
+    MockServer::start(|request| format!("input of {}\n", path(request)))
+}
+
+fn data_dir() -> Arc<DataDir> {
+    common::data_dir("download")
+}
+
+fn event(day: &str) -> Event {
+    Event::new("2023".parse().unwrap(), day.parse().unwrap())
+}
+
+fn christmas() -> DateTime<Utc> {
+    "2023-12-25T12:00:00Z".parse().unwrap()
+}
+
+#[test]
+fn test_downloads_are_cached() {
+    let _data_dir = data_dir();
+    let server = input_server();
+    let client = Client::new(&server.base_url, "secret")
+        .unwrap()
+        .with_request_interval(Duration::ZERO);
+
+    let first = download_input(&client, event("01"), christmas()).unwrap();
+    let body = "input of /2023/day/1/input\n";
+    assert!(matches!(first, Download::Fetched(fetch) if fetch.bytes == body.len()));
+    let second = download_input(&client, event("01"), christmas()).unwrap();
+    assert_eq!(second, Download::Cached);
+
+    assert_eq!(server.requests().len(), 1);
+    assert_eq!(path(&server.requests()[0]), "/2023/day/1/input");
+    assert_eq!(read_to_string(input_path(event("01"))).unwrap(), body);
+    let downloads = read_downloads().unwrap();
+    assert_eq!(downloads["2023/01"].fetched_at, christmas().timestamp());
+}
+
+#[test]
+fn test_requests_are_throttled_across_clients() {
+    let _data_dir = data_dir();
+    let last_request_file = config::data_dir().join("throttle_test_last_request");
+    let server = input_server();
+    let interval = Duration::from_millis(300);
+    let start = Instant::now();
+    for day in ["02", "03"] {
+        let client = Client::new(&server.base_url, "secret")
+            .unwrap()
+            .with_request_interval(interval)
+            .with_last_request_file(&last_request_file);
+        download_input(&client, event(day), christmas()).unwrap();
+    }
+
+    assert!(start.elapsed() >= interval);
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn test_locked_puzzles_are_not_requested() {
+    let _data_dir = data_dir();
+    let server = input_server();
+    let client = Client::new(&server.base_url, "secret").unwrap();
+
+    let before_unlock = "2023-12-05T04:59:59Z".parse().unwrap();
+    assert_eq!(
+        download_input(&client, event("05"), before_unlock).unwrap(),
+        Download::Locked {
+            unlocks_at: "2023-12-05T05:00:00Z".parse().unwrap()
+        }
+    );
+    assert!(server.requests().is_empty());
+    assert!(!input_path(event("05")).exists());
+}
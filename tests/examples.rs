@@ -0,0 +1,90 @@
+//! Runs every example against its expected answers, in place of a test module
+//! per day. Each example part is reported on its own line. An example without
+//! its input fails, and so does a run in which no example passed.
+
+use advent_of_code_rust_criterion::answers::Verdict;
+use advent_of_code_rust_criterion::examples::{diff, examples, run_example};
+use advent_of_code_rust_criterion::{registered_parts, Event, RunConfig};
+use std::process::exit;
+
+fn main() {
+    let mut events: Vec<Event> = registered_parts()
+        .into_iter()
+        .map(|(event, _)| event)
+        .collect();
+    events.dedup();
+
+    let (mut passed, mut skipped) = (0, 0);
+    let mut failures = vec![];
+    for event in events {
+        let examples = match examples(event) {
+            Ok(examples) => examples,
+            Err(why) => {
+                println!("examples of {} ... FAILED", event);
+                failures.push(format!("{}: {}", event, why));
+                continue;
+            }
+        };
+        for example in examples {
+            let results = match run_example(event, &example, &RunConfig::default()) {
+                Ok(results) => results,
+                Err(why) => {
+                    println!("example {} {} ... FAILED", event, example.name);
+                    failures.push(format!(
+                        "{} {}: failed to read {}: {}\n",
+                        event,
+                        example.name,
+                        example.path.display(),
+                        why
+                    ));
+                    continue;
+                }
+            };
+            for result in results {
+                let name = format!("{} {} Part {}", event, example.name, result.part);
+                match result.verdict() {
+                    Verdict::Correct => {
+                        println!("example {} ... ok", name);
+                        passed += 1;
+                    }
+                    Verdict::Unknown => {
+                        println!("example {} ... skipped, no expected answer", name);
+                        skipped += 1;
+                    }
+                    Verdict::Wrong { expected } => {
+                        println!("example {} ... FAILED", name);
+                        let actual = match result.error {
+                            Some(why) => why,
+                            None => result
+                                .answer
+                                .map(|answer| answer.to_string())
+                                .unwrap_or_default(),
+                        };
+                        let expected = expected.to_string();
+                        failures.push(format!("{}\n{}", name, diff(&expected, &actual)));
+                    }
+                }
+            }
+        }
+    }
+
+    if passed == 0 && failures.is_empty() {
+        failures.push("no example ran\n".to_string());
+    }
+    if !failures.is_empty() {
+        println!("\nfailures:\n");
+        for failure in &failures {
+            println!("{}", failure);
+        }
+    }
+    println!(
+        "\nexample result: {}. {} passed; {} failed; {} skipped\n",
+        if failures.is_empty() { "ok" } else { "FAILED" },
+        passed,
+        failures.len(),
+        skipped
+    );
+    if !failures.is_empty() {
+        exit(1);
+    }
+}
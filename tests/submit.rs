@@ -0,0 +1,84 @@
+mod common;
+
+use advent_of_code_rust_criterion::answers::read_answers;
+use advent_of_code_rust_criterion::client::Client;
+use advent_of_code_rust_criterion::submit::{
+    read_bounds, submit_answer, Outcome, Refusal, Submission,
+};
+use advent_of_code_rust_criterion::{Answer, Event, Part, DEFAULT_INPUT};
+use common::MockServer;
+use std::time::Duration;
+
+/// A puzzle server that answers each submission with an article containing
+/// the next of `messages`, like the real one does.
+fn answer_server(messages: Vec<&'static str>) -> MockServer {
+    let mut messages = messages.into_iter();
+    MockServer::start(move |_| {
+        format!(
+            "<html><body><main><article><p>{}</p></article></main></body></html>",
+            messages.next().unwrap()
+        )
+    })
+}
+
+/// The submitted form of a raw request.
+fn form(request: &str) -> &str {
+    request.split_once("\r\n\r\n").unwrap().1
+}
+
+fn event(day: &str) -> Event {
+    Event::new("2023".parse().unwrap(), day.parse().unwrap())
+}
+
+#[test]
+fn test_submit_answer_against_server() {
+    let _data_dir = common::data_dir("submit");
+    let server = answer_server(vec![
+        "That's not the right answer; your answer is too high.",
+        "You gave an answer too recently. You have 45s left to wait.",
+        "That's the right answer! You are one gold star closer.",
+    ]);
+    let client = Client::new(&server.base_url, "secret")
+        .unwrap()
+        .with_request_interval(Duration::ZERO);
+    let day_07 = event("07");
+    let submit = |answer: u32| submit_answer(&client, day_07, Part::A, &Answer::from(answer));
+
+    assert_eq!(submit(7000).unwrap(), Submission::Sent(Outcome::TooHigh));
+    assert_eq!(read_bounds(day_07).unwrap()[&Part::A].high, Some(7000));
+    assert_eq!(
+        submit(7001).unwrap(),
+        Submission::Refused(Refusal::TooHigh { high: 7000 })
+    );
+
+    assert_eq!(
+        submit(6440).unwrap(),
+        Submission::Sent(Outcome::RateLimited {
+            wait: Some(Duration::from_secs(45))
+        })
+    );
+    assert!(read_answers(day_07, DEFAULT_INPUT).unwrap().is_empty());
+
+    assert_eq!(submit(6440).unwrap(), Submission::Sent(Outcome::Correct));
+    assert_eq!(
+        read_answers(day_07, DEFAULT_INPUT).unwrap()[&Part::A],
+        Answer::from(6440u32)
+    );
+
+    let requests = server.requests();
+    assert!(requests[0].starts_with("POST /2023/day/7/answer HTTP/1.1\r\n"));
+    assert!(requests[0]
+        .to_lowercase()
+        .contains("cookie: session=secret\r\n"));
+    assert_eq!(
+        requests
+            .iter()
+            .map(|request| form(request))
+            .collect::<Vec<_>>(),
+        [
+            "level=1&answer=7000",
+            "level=1&answer=6440",
+            "level=1&answer=6440"
+        ]
+    );
+}